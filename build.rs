@@ -1,61 +1,517 @@
 use std::{
-    collections::HashSet,
+    collections::{BTreeMap, HashSet},
     env,
-    path::PathBuf,
-    sync::{Arc, RwLock},
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
 };
 
-use bindgen::callbacks::{MacroParsingBehavior, ParseCallbacks};
+#[cfg(feature = "bindgen")]
+use std::sync::{Arc, RwLock};
+
+#[cfg(feature = "bindgen")]
+use bindgen::callbacks::{IntKind, ParseCallbacks};
 
 // build script heavily inspired by proj-sys crate
 // some parts of code from rust-bindgen
 
 // currently the latest in apt on Github Actions
 const MINIMUM_ECCODES_VERSION: &str = "2.24.0";
-const PROBLEMATIC_MACROS: [&str; 5] = [
-    "FP_NAN",
-    "FP_INFINITE",
-    "FP_ZERO",
-    "FP_SUBNORMAL",
-    "FP_NORMAL",
-];
 
+// ECCODES_VERSION encodes minor version in two digits,
+// so every major version has at most this many minor versions
+const MAX_MINOR: u32 = 99;
+
+/// Error codes defined in ecCodes headers, by macro name.
+type ErrorCodes = BTreeMap<String, i64>;
+
+#[cfg(feature = "bindgen")]
 #[derive(Debug)]
 struct MacroCallback {
-    macros: Arc<RwLock<HashSet<String>>>,
+    error_codes: Arc<RwLock<ErrorCodes>>,
 }
 
+#[cfg(feature = "bindgen")]
 impl ParseCallbacks for MacroCallback {
-    fn will_parse_macro(&self, name: &str) -> MacroParsingBehavior {
-        self.macros.write().unwrap().insert(name.into());
-
-        if PROBLEMATIC_MACROS.contains(&name) {
-            return MacroParsingBehavior::Ignore;
+    fn int_macro(&self, name: &str, value: i64) -> Option<IntKind> {
+        if name.starts_with("GRIB_") && value < 0 {
+            self.error_codes.write().unwrap().insert(name.into(), value);
         }
 
-        MacroParsingBehavior::Default
+        None
     }
 }
 
 fn main() {
-    if cfg!(feature = "docs") {
-        return;
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    let (version, error_codes, thread_safe) = if cfg!(feature = "docs") {
+        // documentation is built with checked-in bindings, without ecCodes
+        let docs_bindings_path = manifest_dir().join("src").join("bindings-docs.rs");
+        rerun_if_changed(&docs_bindings_path);
+        let docs_bindings =
+            fs::read_to_string(&docs_bindings_path).expect("Failed to read documentation bindings");
+        emit_default_paths(None, None, &out_dir.join("default_paths.rs"));
+
+        (
+            version_from_bindings(&docs_bindings),
+            error_codes_from_bindings(&docs_bindings),
+            false,
+        )
+    } else {
+        let installation = if cfg!(feature = "vendored") {
+            build_vendored()
+        } else if let Some(installation) = link_from_env() {
+            installation
+        } else {
+            probe_system()
+        };
+
+        emit_installation(&installation, &out_dir.join("default_paths.rs"));
+
+        let include_path = installation.include_dir;
+
+        for header in [
+            "eccodes_version.h",
+            "eccodes_config.h",
+            "eccodes_ecbuild_config.h",
+        ] {
+            rerun_if_changed(&include_path.join(header));
+        }
+
+        let version = header_version(&include_path);
+        let thread_safe = header_thread_safe(&include_path);
+        let bindings_path = out_dir.join("bindings.rs");
+
+        let error_codes = if cfg!(feature = "bindgen") {
+            generate_bindings(&include_path, &bindings_path)
+        } else {
+            let version = version.as_deref().unwrap_or_else(|| {
+                panic!(
+                    "Cannot read ecCodes version from headers in {}, which is required to select pre-generated bindings.
+                    Please enable the `bindgen` feature to generate bindings instead (requires libclang).",
+                    include_path.display()
+                )
+            });
+            copy_pregenerated_bindings(version, &bindings_path)
+        };
+
+        (version, error_codes, thread_safe)
     };
+
+    if version.is_none() {
+        eprintln!("Cannot detect ecCodes version, version cfgs will not be set");
+    }
+
+    emit_version(version.as_deref());
+
+    emit_error_codes(&error_codes, &out_dir.join("error_codes.rs"));
+    emit_thread_safe(thread_safe);
+}
+
+/// Directories of the ecCodes installation that the crate is linked against.
+struct Installation {
+    prefix: PathBuf,
+    include_dir: PathBuf,
+    lib_dir: PathBuf,
+}
+
+impl Installation {
+    /// Creates installation with prefix assumed from the standard `<prefix>/include` layout.
+    fn new(include_dir: PathBuf, lib_dir: PathBuf) -> Self {
+        Installation {
+            prefix: include_dir.parent().unwrap_or(&include_dir).to_path_buf(),
+            include_dir,
+            lib_dir,
+        }
+    }
+
+    /// Returns the default path of ecCodes data directory `kind` (`definitions` or `samples`),
+    /// which is compiled into the library as `ECCODES_DEFINITION_PATH` or `ECCODES_SAMPLES_PATH`.
+    /// If the configuration header does not define it, `<prefix>/share/eccodes/<kind>` is used if it exists.
+    fn default_data_path(&self, kind: &str) -> Option<String> {
+        let define = match kind {
+            "definitions" => "ECCODES_DEFINITION_PATH",
+            _ => "ECCODES_SAMPLES_PATH",
+        };
+
+        let header_path = fs::read_to_string(self.include_dir.join("eccodes_config.h"))
+            .ok()
+            .and_then(|header| {
+                header.lines().find_map(|line| {
+                    let value = line.trim().strip_prefix("#define")?.trim_start();
+                    let value = value.strip_prefix(define)?;
+                    let path = value.trim().trim_matches('"');
+                    (value.starts_with(char::is_whitespace) && !path.is_empty())
+                        .then(|| path.to_string())
+                })
+            });
+
+        header_path.or_else(|| {
+            let dir = self.prefix.join("share").join("eccodes").join(kind);
+            dir.is_dir().then(|| dir.to_string_lossy().into_owned())
+        })
+    }
+}
+
+/// Exports directories of the linked ecCodes as `DEP_ECCODES_INCLUDE`, `DEP_ECCODES_LIB_DIR`,
+/// `DEP_ECCODES_DEFINITIONS` and `DEP_ECCODES_SAMPLES` for dependent crates,
+/// so that they can compile C code against the same installation, and generates
+/// `DEFAULT_DEFINITIONS_PATH` and `DEFAULT_SAMPLES_PATH` constants.
+/// Data directories are exported only if they have been found.
+fn emit_installation(installation: &Installation, out_path: &Path) {
+    println!("cargo:include={}", installation.include_dir.display());
+    println!("cargo:lib_dir={}", installation.lib_dir.display());
+
+    let definitions = installation.default_data_path("definitions");
+    let samples = installation.default_data_path("samples");
+
+    for (key, path) in [("definitions", &definitions), ("samples", &samples)] {
+        match path {
+            Some(path) => println!("cargo:{}={}", key, path),
+            None => eprintln!("Cannot find default ecCodes {} directory", key),
+        }
+    }
+
+    emit_default_paths(definitions.as_deref(), samples.as_deref(), out_path);
+}
+
+/// Writes `DEFAULT_DEFINITIONS_PATH` and `DEFAULT_SAMPLES_PATH` constants into `out_path`.
+fn emit_default_paths(definitions: Option<&str>, samples: Option<&str>, out_path: &Path) {
+    let mut code = String::new();
+
+    for (name, kind, path) in [
+        ("DEFAULT_DEFINITIONS_PATH", "definitions", definitions),
+        ("DEFAULT_SAMPLES_PATH", "samples", samples),
+    ] {
+        writeln!(
+            code,
+            "/// Default {} path of the linked ecCodes, as found at build time.",
+            kind
+        )
+        .unwrap();
+        writeln!(code, "pub const {}: Option<&str> = {:?};", name, path).unwrap();
+    }
+
+    fs::write(out_path, code).expect("Failed to write default paths");
+}
+
+/// Exports ecCodes version as `DEP_ECCODES_VERSION` for dependent crates
+/// and emits `eccodes_<major>_<minor>` cfg for the detected and every older minor version,
+/// including all minor versions of older major versions, so that APIs of newer ecCodes versions
+/// can be gated with eg. `#[cfg(eccodes_2_30)]`. The cfgs are declared also when the version
+/// has not been detected, so that gated code does not trigger `unexpected_cfgs`.
+fn emit_version(version: Option<&str>) {
+    if let Some(version) = version {
+        println!("cargo:version={}", version);
+    }
+
+    let detected = version.map(version_tuple);
+    let major = detected.map_or(2, |(major, _, _)| major);
+
+    // ecCodes versions start at 2.0
+    let mut version_cfgs = Vec::new();
+    for m in major.min(2)..=major.max(2) {
+        for n in 0..=MAX_MINOR {
+            let version_cfg = format!("eccodes_{}_{}", m, n);
+
+            if detected.is_some_and(|(major, minor, _)| (m, n) <= (major, minor)) {
+                println!("cargo:rustc-cfg={}", version_cfg);
+            }
+
+            version_cfgs.push(version_cfg);
+        }
+    }
+
+    println!("cargo:rustc-check-cfg=cfg({})", version_cfgs.join(", "));
+}
+
+/// Generates bindings from ecCodes headers with bindgen
+/// and returns error codes found in the headers.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(include_path: &Path, out_path: &Path) -> ErrorCodes {
+    let error_codes = Arc::new(RwLock::new(ErrorCodes::new()));
+    rerun_if_changed(&manifest_dir().join("wrapper.h"));
+
+    let tests = cfg!(feature = "tests");
+
+    // only ecCodes API is generated, so that bindings do not contain
+    // libc and libm items, which differ between systems
+    let bindings = bindgen::Builder::default()
+        .clang_arg(format!("-I{}", include_path.to_string_lossy()))
+        .trust_clang_mangling(false)
+        .header("wrapper.h")
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+        .allowlist_function("(codes|grib|wmo|bufr)_.*")
+        .allowlist_function("parse_keyval_string")
+        .allowlist_type("(codes|grib|wmo|bufr)_.*")
+        .allowlist_type("ProductKind")
+        .allowlist_var("(CODES|GRIB|ECCODES)_.*")
+        .blocklist_type("FILE")
+        .blocklist_type("off_t")
+        .raw_line("pub use libc::{FILE, off_t};")
+        .layout_tests(tests) //avoiding tests with UB
+        .parse_callbacks(Box::new(MacroCallback {
+            error_codes: error_codes.clone(),
+        }))
+        .generate()
+        .expect("Unable to generate bindings, libclang is required to generate them");
+
+    bindings
+        .write_to_file(out_path)
+        .expect("Failed to write bindings to file");
+
+    error_codes.read().unwrap().clone()
+}
+
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings(_include_path: &Path, _out_path: &Path) -> ErrorCodes {
+    unreachable!("bindings generation requested without the `bindgen` feature")
+}
+
+/// Copies checked-in bindings matching the version of linked ecCodes
+/// and returns error codes defined in them.
+fn copy_pregenerated_bindings(version: &str, out_path: &Path) -> ErrorCodes {
+    let target_family = env::var("CARGO_CFG_TARGET_FAMILY").unwrap();
+    let pointer_width = env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap();
+
+    // pre-generated bindings depend only on C types layout, which is the same on LP64 targets
+    if !target_family.split(',').any(|f| f == "unix") || pointer_width != "64" {
+        panic!(
+            "Pre-generated bindings are only available for 64-bit Unix targets.
+            Please enable the `bindgen` feature to generate bindings for this target (requires libclang)."
+        );
+    }
+
+    let (major, minor, _) = version_tuple(version);
+    let minor_version = format!("{}.{}", major, minor);
+
+    let pregenerated_path = pregenerated_dir().join(format!("bindings-{}.rs", minor_version));
+    rerun_if_changed(&pregenerated_path);
+
+    if !pregenerated_path.is_file() {
+        panic!(
+            "There are no pre-generated bindings for ecCodes {} (available versions: {}).
+            Please enable the `bindgen` feature to generate bindings for this version (requires libclang).",
+            version,
+            pregenerated_versions().join(", ")
+        );
+    }
+
+    eprintln!(
+        "Using pre-generated bindings for ecCodes {}: {:?}",
+        version, pregenerated_path
+    );
+
+    let bindings =
+        fs::read_to_string(&pregenerated_path).expect("Failed to read pre-generated bindings");
+    let bindings = replace_version_constants(&bindings, version);
+    fs::write(out_path, &bindings).expect("Failed to write bindings to file");
+
+    error_codes_from_bindings(&bindings)
+}
+
+/// Directory with bindings checked-in as `bindings-<major>.<minor>.rs`.
+/// Bindings for a new version are added by copying `bindings.rs` generated with the `bindgen` feature.
+fn pregenerated_dir() -> PathBuf {
+    manifest_dir().join("src").join("pregenerated")
+}
+
+/// Returns ecCodes minor versions with checked-in bindings.
+fn pregenerated_versions() -> Vec<String> {
+    println!("cargo:rerun-if-changed={}", pregenerated_dir().display());
+
+    let mut versions = fs::read_dir(pregenerated_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let version = name.strip_prefix("bindings-")?.strip_suffix(".rs")?;
+            Some(version.to_string())
+        })
+        .collect::<Vec<_>>();
+
+    versions.sort_by_key(|version| version_tuple(version));
+    versions
+}
+
+/// Replaces `ECCODES_VERSION*` constants of pre-generated bindings, which come from
+/// the release they have been generated with, with the version of linked ecCodes.
+fn replace_version_constants(bindings: &str, version: &str) -> String {
+    let (major, minor, revision) = version_tuple(version);
+
+    let constants = [
+        (
+            "ECCODES_VERSION_STR",
+            format!("&[u8; {}] = b\"{}\\0\"", version.len() + 1, version),
+        ),
+        ("ECCODES_MAJOR_VERSION", format!("u32 = {}", major)),
+        ("ECCODES_MINOR_VERSION", format!("u32 = {}", minor)),
+        ("ECCODES_REVISION_VERSION", format!("u32 = {}", revision)),
+        (
+            "ECCODES_VERSION",
+            format!("u32 = {}", major * 10000 + minor * 100 + revision),
+        ),
+    ];
+
+    bindings
+        .lines()
+        .map(|line| {
+            constants
+                .iter()
+                .find(|(name, _)| line.starts_with(&format!("pub const {}:", name)))
+                .map_or_else(
+                    || line.to_string(),
+                    |(name, value)| format!("pub const {}: {};", name, value),
+                )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reads error codes from `GRIB_*` constants in bindings generated by bindgen.
+fn error_codes_from_bindings(bindings: &str) -> ErrorCodes {
+    bindings
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line
+                .trim()
+                .strip_prefix("pub const ")?
+                .strip_suffix(';')?
+                .split_once(": i32 = ")?;
+
+            let value = value.trim().parse::<i64>().ok()?;
+
+            (name.starts_with("GRIB_") && value < 0).then(|| (name.to_string(), value))
+        })
+        .collect()
+}
+
+/// Emits `eccodes_error` cfg for each error code defined by linked ecCodes,
+/// so that variants of `CodesError` for codes added in newer versions can be gated,
+/// and writes compile-time assertions that every code has its own variant.
+/// The assertions are checked when the `tests` feature is enabled.
+fn emit_error_codes(error_codes: &ErrorCodes, out_path: &Path) {
+    println!("cargo:rustc-check-cfg=cfg(eccodes_error, values(any()))");
+
+    let mut assertions = String::new();
+    writeln!(
+        assertions,
+        "// Error codes defined in headers of ecCodes this crate is built with."
+    )
+    .unwrap();
+    writeln!(assertions, "#[cfg(feature = \"tests\")]").unwrap();
+    writeln!(assertions, "const _: () = {{").unwrap();
+
+    for (name, value) in error_codes {
+        println!("cargo:rustc-cfg=eccodes_error=\"{}\"", name);
+
+        writeln!(
+            assertions,
+            "    assert!(CodesError::is_known_code({}), \"{} is not represented in CodesError\");",
+            value, name
+        )
+        .unwrap();
+    }
+
+    writeln!(assertions, "}};").unwrap();
+
+    fs::write(out_path, assertions).expect("Failed to write error codes assertions");
+}
+
+/// Reruns the build script when the file at `path` changes.
+/// Files that do not exist are skipped, as cargo would rerun the script on every build.
+fn rerun_if_changed(path: &Path) {
+    if path.is_file() {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+}
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+}
+
+/// Links ecCodes installed in the system and returns its directories.
+fn probe_system() -> Installation {
+    let static_linking = link_statically();
+
+    // link lines are emitted manually, because pkg-config
+    // refuses to link libraries from system directories statically
     let lib_result = pkg_config::Config::new()
         .atleast_version(MINIMUM_ECCODES_VERSION)
+        .statik(static_linking)
+        .cargo_metadata(false)
+        .env_metadata(true)
         .probe("eccodes");
 
-    let include_path = match lib_result {
+    match lib_result {
         Ok(pk) => {
-            eprintln!(
-                "Found installed ecCodes library to link at: {:?}",
-                pk.link_paths[0]
-            );
-            println!("cargo:rustc-link-search={:?}", pk.link_paths[0]);
-            println!("cargo:rustc-link-lib=eccodes");
-            pk.include_paths[0].clone()
+            let (Some(include_dir), Some(lib_dir)) =
+                (pk.include_paths.first(), pk.link_paths.first())
+            else {
+                panic!(
+                    "pkg-config found ecCodes, but its include or library directory is missing (include: {:?}, lib: {:?}).
+                    Please check the eccodes.pc file or set ECCODES_DIR to the ecCodes installation prefix.",
+                    pk.include_paths, pk.link_paths
+                );
+            };
+
+            eprintln!("Found installed ecCodes library to link at: {:?}", lib_dir);
+
+            for link_path in &pk.link_paths {
+                println!("cargo:rustc-link-search=native={}", link_path.display());
+            }
+
+            if static_linking {
+                let static_lib_found = pk
+                    .link_paths
+                    .iter()
+                    .any(|path| path.join("libeccodes.a").is_file());
+
+                if !static_lib_found {
+                    panic!(
+                        "Static linking of ecCodes has been requested, but libeccodes.a 
+                        has not been found in any of the library paths: {:?}",
+                        pk.link_paths
+                    );
+                }
+            }
+
+            if static_linking {
+                println!("cargo:rustc-link-lib=static=eccodes");
+            } else {
+                println!("cargo:rustc-link-lib=eccodes");
+            }
+
+            // dependencies must come after ecCodes for the linker to keep them
+            let mut linked_libs = HashSet::from(["eccodes"]);
+            for lib in &pk.libs {
+                if linked_libs.insert(lib) {
+                    println!("cargo:rustc-link-lib={}", lib);
+                }
+            }
+
+            if static_linking {
+                link_static_system_libs();
+            }
+
+            let mut installation = Installation::new(include_dir.clone(), lib_dir.clone());
+            if let Ok(prefix) = pkg_config::get_variable("eccodes", "prefix") {
+                installation.prefix = PathBuf::from(prefix);
+            }
+
+            installation
         }
         Err(err) => {
+            if let Some(prefix) = find_cmake_prefix() {
+                eprintln!(
+                    "pkg-config failed to find ecCodes, using CMake package at: {:?}",
+                    prefix
+                );
+                let lib_dir = prefix_lib_dir(&prefix);
+                return link_installation(prefix.join("include"), lib_dir);
+            }
+
             panic!(
                 "Cannot find existing ecCodes library. 
                 Please check the README for information how to correctly install ecCodes.
@@ -63,24 +519,331 @@ fn main() {
                 err
             );
         }
+    }
+}
+
+/// Links ecCodes from directories set with `ECCODES_DIR`, `ECCODES_LIB_DIR`
+/// and `ECCODES_INCLUDE_DIR` environmental variables and returns its directories.
+/// Returns `None` if none of the variables is set.
+fn link_from_env() -> Option<Installation> {
+    println!("cargo:rerun-if-env-changed=ECCODES_DIR");
+    println!("cargo:rerun-if-env-changed=ECCODES_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=ECCODES_INCLUDE_DIR");
+
+    let prefix = env::var_os("ECCODES_DIR").map(PathBuf::from);
+    let lib_dir = env::var_os("ECCODES_LIB_DIR").map(PathBuf::from);
+    let include_dir = env::var_os("ECCODES_INCLUDE_DIR").map(PathBuf::from);
+
+    let (include_dir, lib_dir) = match (prefix, include_dir, lib_dir) {
+        (None, None, None) => return None,
+        (Some(prefix), include_dir, lib_dir) => (
+            include_dir.unwrap_or_else(|| prefix.join("include")),
+            lib_dir.unwrap_or_else(|| prefix_lib_dir(&prefix)),
+        ),
+        (None, Some(include_dir), Some(lib_dir)) => (include_dir, lib_dir),
+        (None, _, _) => panic!(
+            "Both ECCODES_LIB_DIR and ECCODES_INCLUDE_DIR must be set
+            when ecCodes location is not specified with ECCODES_DIR"
+        ),
     };
 
-    //bindgen magic to avoid duplicate math.h type definitions
-    let macros = Arc::new(RwLock::new(HashSet::new()));
+    eprintln!(
+        "Using ecCodes library from environmental variables at: {:?}",
+        lib_dir
+    );
 
-    let tests = cfg!(feature = "tests");
+    Some(link_installation(include_dir, lib_dir))
+}
 
-    let bindings = bindgen::Builder::default()
-        .clang_arg(format!("-I{}", include_path.to_string_lossy()))
-        .trust_clang_mangling(false)
-        .header("wrapper.h")
-        .layout_tests(tests) //avoiding tests with UB
-        .parse_callbacks(Box::new(MacroCallback { macros }))
-        .generate()
-        .expect("Unable to generate bindings");
+/// Links ecCodes from given directories without pkg-config.
+fn link_installation(include_dir: PathBuf, lib_dir: PathBuf) -> Installation {
+    if !include_dir.join("eccodes.h").is_file() {
+        panic!(
+            "ecCodes headers have not been found in: {}",
+            include_dir.display()
+        );
+    }
 
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    bindings
-        .write_to_file(out_path.join("bindings.rs"))
-        .expect("Failed to write bindings to file");
+    match header_version(&include_dir) {
+        Some(version) if version_tuple(&version) < version_tuple(MINIMUM_ECCODES_VERSION) => {
+            panic!(
+                "ecCodes found in {} has version {}, but at least {} is required",
+                include_dir.display(),
+                version,
+                MINIMUM_ECCODES_VERSION
+            );
+        }
+        Some(_) => (),
+        None => eprintln!(
+            "Cannot read ecCodes version from headers in: {:?}",
+            include_dir
+        ),
+    }
+
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+
+    if link_statically() {
+        println!("cargo:rustc-link-lib=static=eccodes");
+        link_static_dependencies();
+        link_static_system_libs();
+    } else {
+        println!("cargo:rustc-link-lib=eccodes");
+    }
+
+    Installation::new(include_dir, lib_dir)
+}
+
+/// Looks for `eccodes-config.cmake` in locations searched by CMake
+/// and returns the installation prefix it belongs to.
+fn find_cmake_prefix() -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed=eccodes_DIR");
+    println!("cargo:rerun-if-env-changed=CMAKE_PREFIX_PATH");
+
+    let mut config_dirs = Vec::new();
+
+    if let Some(dir) = env::var_os("eccodes_DIR") {
+        config_dirs.push(PathBuf::from(dir));
+    }
+
+    if let Some(prefixes) = env::var_os("CMAKE_PREFIX_PATH") {
+        // CMake accepts both separators in this variable
+        let prefixes = prefixes.to_string_lossy().replace(';', ":");
+
+        for prefix in prefixes.split(':').filter(|p| !p.is_empty()) {
+            for subdir in [
+                "lib/cmake/eccodes",
+                "lib64/cmake/eccodes",
+                "share/eccodes/cmake",
+            ] {
+                config_dirs.push(Path::new(prefix).join(subdir));
+            }
+        }
+    }
+
+    config_dirs
+        .iter()
+        .filter(|dir| dir.join("eccodes-config.cmake").is_file())
+        .find_map(|dir| {
+            dir.ancestors()
+                .find(|prefix| prefix.join("include").join("eccodes.h").is_file())
+                .map(Path::to_path_buf)
+        })
+}
+
+/// Returns the library directory of ecCodes installed in `prefix`.
+fn prefix_lib_dir(prefix: &Path) -> PathBuf {
+    ["lib64", "lib"]
+        .iter()
+        .map(|dir| prefix.join(dir))
+        .find(|dir| {
+            ["libeccodes.so", "libeccodes.a", "libeccodes.dylib"]
+                .iter()
+                .any(|lib| dir.join(lib).is_file())
+        })
+        .unwrap_or_else(|| prefix.join("lib"))
+}
+
+/// Checks in configuration headers whether ecCodes has been built with thread support,
+/// either with POSIX threads (`GRIB_PTHREADS`) or OpenMP (`GRIB_OMP_THREADS`).
+fn header_thread_safe(include_dir: &Path) -> bool {
+    ["eccodes_config.h", "eccodes_ecbuild_config.h"]
+        .iter()
+        .filter_map(|header| fs::read_to_string(include_dir.join(header)).ok())
+        .any(|header| {
+            header.lines().any(|line| {
+                let Some(define) = line.trim().strip_prefix("#define") else {
+                    return false;
+                };
+                let mut parts = define.split_whitespace();
+
+                matches!(
+                    parts.next(),
+                    Some("GRIB_PTHREADS") | Some("GRIB_OMP_THREADS")
+                ) && parts.next().is_some_and(|value| value != "0")
+            })
+        })
+}
+
+/// Emits `eccodes_thread_safe` cfg when ecCodes has been built with thread support.
+fn emit_thread_safe(thread_safe: bool) {
+    println!("cargo:rustc-check-cfg=cfg(eccodes_thread_safe)");
+
+    if thread_safe {
+        println!("cargo:rustc-cfg=eccodes_thread_safe");
+    } else {
+        eprintln!("ecCodes has been built without thread support, locks in `sync` will be used");
+    }
+}
+
+/// Reads ecCodes version from `eccodes_version.h` in the include directory.
+fn header_version(include_dir: &Path) -> Option<String> {
+    let header = fs::read_to_string(include_dir.join("eccodes_version.h")).ok()?;
+
+    header.lines().find_map(|line| {
+        let version = line.trim().strip_prefix("#define ECCODES_VERSION_STR")?;
+        Some(version.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Reads ecCodes version from `ECCODES_VERSION_STR` constant in bindings generated by bindgen.
+fn version_from_bindings(bindings: &str) -> Option<String> {
+    bindings.lines().find_map(|line| {
+        let (_, version) = line
+            .trim()
+            .strip_prefix("pub const ECCODES_VERSION_STR")?
+            .split_once("b\"")?;
+        Some(version.split_once('\\')?.0.to_string())
+    })
+}
+
+/// Converts version string into comparable `(major, minor, patch)` tuple.
+fn version_tuple(version: &str) -> (u32, u32, u32) {
+    let mut parts = version
+        .split('.')
+        .map(|part| part.trim().parse::<u32>().unwrap_or(0));
+
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
+
+/// Checks whether static linking has been requested
+/// with the `static` feature or `ECCODES_STATIC` environmental variable.
+fn link_statically() -> bool {
+    println!("cargo:rerun-if-env-changed=ECCODES_STATIC");
+
+    let env_static = env::var("ECCODES_STATIC").is_ok_and(|v| !v.is_empty() && v != "0");
+
+    cfg!(feature = "static") || env_static
+}
+
+/// Builds ecCodes from the source tree pointed to by `ECCODES_SOURCE_DIR`,
+/// links it statically and returns directories of the build.
+/// The feature enables `bindgen`, so bindings are generated from the built headers.
+#[cfg(feature = "vendored")]
+fn build_vendored() -> Installation {
+    println!("cargo:rerun-if-env-changed=ECCODES_SOURCE_DIR");
+
+    let source_dir = match env::var_os("ECCODES_SOURCE_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => panic!(
+            "The `vendored` feature requires ecCodes sources.
+            Please set ECCODES_SOURCE_DIR to the directory of unpacked ecCodes source tarball."
+        ),
+    };
+
+    if !source_dir.join("CMakeLists.txt").is_file() {
+        panic!(
+            "ECCODES_SOURCE_DIR ({}) does not contain ecCodes sources",
+            source_dir.display()
+        );
+    }
+
+    let jpg = vendored_component("JPG", false);
+    let aec = vendored_component("AEC", false);
+    let png = vendored_component("PNG", false);
+    let memfs = vendored_component("MEMFS", true);
+    let on_off = |enabled: bool| if enabled { "ON" } else { "OFF" };
+
+    // only the C library is needed, so everything else that pulls additional
+    // dependencies or toolchains is turned off
+    let install_dir = cmake::Config::new(&source_dir)
+        .define("BUILD_SHARED_LIBS", "OFF")
+        .define("CMAKE_POSITION_INDEPENDENT_CODE", "ON")
+        .define("ENABLE_FORTRAN", "OFF")
+        .define("ENABLE_PYTHON", "OFF")
+        .define("ENABLE_NETCDF", "OFF")
+        .define("ENABLE_JPG", on_off(jpg))
+        .define("ENABLE_JPG_LIBOPENJPEG", on_off(jpg))
+        .define("ENABLE_JPG_LIBJASPER", "OFF")
+        .define("ENABLE_PNG", on_off(png))
+        .define("ENABLE_AEC", on_off(aec))
+        .define("ENABLE_MEMFS", on_off(memfs))
+        .define("ENABLE_EXAMPLES", "OFF")
+        .define("ENABLE_TESTS", "OFF")
+        .define("ENABLE_BUILD_TOOLS", "OFF")
+        .build();
+
+    eprintln!("Built vendored ecCodes library at: {:?}", install_dir);
+
+    let lib_dir = prefix_lib_dir(&install_dir);
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    println!("cargo:rustc-link-lib=static=eccodes");
+
+    // dependencies of enabled components come from the system
+    for (enabled, libs) in [
+        (jpg, &["openjp2"][..]),
+        (aec, &["aec"]),
+        (png, &["png", "z"]),
+    ] {
+        if enabled {
+            for lib in libs {
+                println!("cargo:rustc-link-lib={}", lib);
+            }
+        }
+    }
+
+    link_static_system_libs();
+
+    Installation {
+        include_dir: install_dir.join("include"),
+        prefix: install_dir,
+        lib_dir,
+    }
+}
+
+/// Checks whether optional component of vendored ecCodes is enabled
+/// with `ECCODES_ENABLE_<name>` environmental variable, returns `default` if it is not set.
+#[cfg(feature = "vendored")]
+fn vendored_component(name: &str, default: bool) -> bool {
+    let var = format!("ECCODES_ENABLE_{}", name);
+    println!("cargo:rerun-if-env-changed={}", var);
+
+    env::var(&var).map_or(default, |v| !v.is_empty() && v != "0")
+}
+
+#[cfg(not(feature = "vendored"))]
+fn build_vendored() -> Installation {
+    unreachable!("vendored build requested without the `vendored` feature")
+}
+
+/// Links libraries that static ecCodes found without pkg-config depends on,
+/// which are listed in `ECCODES_STATIC_LIBS` environmental variable.
+fn link_static_dependencies() {
+    println!("cargo:rerun-if-env-changed=ECCODES_STATIC_LIBS");
+
+    let Ok(libs) = env::var("ECCODES_STATIC_LIBS") else {
+        panic!(
+            "Static linking of ecCodes found without pkg-config requires libraries it depends on
+            to be listed in ECCODES_STATIC_LIBS, eg. \"aec openjp2 png z\".
+            Please set it to an empty value if ecCodes has been built without external dependencies."
+        );
+    };
+
+    for lib in libs
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|lib| !lib.is_empty())
+    {
+        println!("cargo:rustc-link-lib={}", lib);
+    }
+}
+
+/// Links libraries that static ecCodes depends on and which
+/// are always provided by the system, but are not listed by pkg-config.
+fn link_static_system_libs() {
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+
+    // recent ecCodes versions contain C++ sources
+    match target_os.as_str() {
+        "macos" | "ios" | "freebsd" => println!("cargo:rustc-link-lib=c++"),
+        "windows" => (),
+        _ => println!("cargo:rustc-link-lib=stdc++"),
+    }
+
+    if target_os != "windows" {
+        println!("cargo:rustc-link-lib=m");
+        println!("cargo:rustc-link-lib=pthread");
+    }
 }
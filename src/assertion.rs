@@ -0,0 +1,82 @@
+//! Recording ecCodes assertion failures instead of aborting the process.
+//!
+//! By default ecCodes calls `abort()` when its internal assertion fails, eg. on some corrupted messages.
+//! After [`install`], the failure message is stored in a thread-local and the next call of
+//! [`check`](crate::error::check) on the same thread returns [`CodesError::AssertionFailure`](crate::error::CodesError::AssertionFailure),
+//! regardless of the value returned by ecCodes. The message can be retrieved with [`take_message`].
+//!
+//! Continuing after a failed assertion is best-effort. ecCodes does not expect the assertion
+//! procedure to return and simply continues executing the function in which the assertion failed,
+//! with the invalid state that caused the failure. The function may then return garbage, report
+//! another error or crash, and no ecCodes function has been verified to recover correctly.
+//! Objects involved in the failed call (handles, indexes, iterators, files being read etc.)
+//! must always be discarded and never passed to ecCodes again. Failed memory allocations
+//! cannot be survived at all, as ecCodes dereferences the pointer it could not allocate.
+//!
+//! Only calls that are followed by [`check`](crate::error::check) report the failure,
+//! so functions that do not return an error code should be followed by [`take_message`].
+
+use std::{cell::RefCell, ffi::CStr, os::raw::c_char};
+
+use crate::*;
+
+thread_local! {
+    static FAILED_ASSERTION: RefCell<Option<FailedAssertion>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug)]
+struct FailedAssertion {
+    message: String,
+    reported: bool,
+}
+
+/// Installs a procedure that records ecCodes assertion failures instead of aborting the process.
+/// The procedure is global and applies to all contexts.
+pub fn install() {
+    crate::version::guard();
+    let _lock = crate::sync::CONTEXT.lock();
+
+    unsafe {
+        codes_set_codes_assertion_failed_proc(Some(record_failure));
+    }
+}
+
+/// Returns the message of the last assertion failure on the current thread and clears it,
+/// so that subsequent calls of [`check`](crate::error::check) are not affected.
+pub fn take_message() -> Option<String> {
+    FAILED_ASSERTION
+        .with_borrow_mut(Option::take)
+        .map(|failure| failure.message)
+}
+
+/// Marks the assertion failure on the current thread as reported
+/// and returns whether there was one that had not been reported yet.
+pub(crate) fn report_failure() -> bool {
+    FAILED_ASSERTION.with_borrow_mut(|failure| match failure {
+        Some(failure) if !failure.reported => {
+            failure.reported = true;
+            true
+        }
+        _ => false,
+    })
+}
+
+unsafe extern "C" fn record_failure(message: *const c_char) {
+    let message = if message.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .into_owned()
+    };
+
+    // thread-local is not accessible while the thread is being destroyed
+    let _ = FAILED_ASSERTION.try_with(|failure| {
+        if let Ok(mut failure) = failure.try_borrow_mut() {
+            *failure = Some(FailedAssertion {
+                message,
+                reported: false,
+            });
+        }
+    });
+}
@@ -0,0 +1,181 @@
+//! Configuration of definitions and samples paths of a context.
+//!
+//! ecCodes searches colon-separated lists of directories for definitions and samples,
+//! using the first file found. [`ContextConfig`] builds such lists from a base directory
+//! and directories with local overrides, eg. custom local GRIB2 tables shipped with an application,
+//! and sets them on a context with `codes_context_set_definitions_path` and `codes_context_set_samples_path`.
+//!
+//! Paths should be configured before any message is decoded, as ecCodes caches definitions it has already loaded.
+//!
+//! ecCodes does not provide a public constructor of contexts, so the configuration can be applied
+//! to the default context or to a context obtained from ecCodes in another way.
+//!
+//! Default paths compiled into the linked ecCodes are available at build time as [`DEFAULT_DEFINITIONS_PATH`]
+//! and [`DEFAULT_SAMPLES_PATH`], eg. for bundling the definitions with an application.
+
+use std::{
+    error::Error,
+    ffi::{CStr, CString},
+    fmt::{self, Display},
+    os::raw::c_char,
+    path::PathBuf,
+};
+
+use crate::*;
+
+/// Builder of definitions and samples paths of a context.
+#[derive(Debug, Clone, Default)]
+pub struct ContextConfig {
+    definitions: PathList,
+    samples: PathList,
+}
+
+#[derive(Debug, Clone, Default)]
+struct PathList {
+    local: Vec<PathBuf>,
+    base: Option<PathBuf>,
+}
+
+/// Error returned when [`ContextConfig`] cannot be applied.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContextConfigError {
+    /// The path does not exist or is not a directory.
+    NotADirectory(PathBuf),
+    /// The path contains a colon or a nul byte and cannot be put into a path list.
+    InvalidPath(PathBuf),
+}
+
+impl Display for ContextConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContextConfigError::NotADirectory(path) => {
+                write!(f, "{} is not an existing directory", path.display())
+            }
+            ContextConfigError::InvalidPath(path) => write!(
+                f,
+                "{} cannot be used in ecCodes path list, as it contains a colon or a nul byte",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for ContextConfigError {}
+
+impl ContextConfig {
+    /// Creates a configuration that does not change any path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the base definitions directory, which is searched after all local directories.
+    /// If not set, the current definitions path of the context is used as the base.
+    pub fn definitions_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.definitions.base = Some(path.into());
+        self
+    }
+
+    /// Adds a directory with local definitions, which is searched before the base definitions path.
+    /// Directories added earlier are searched first.
+    pub fn local_definitions_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.definitions.local.push(path.into());
+        self
+    }
+
+    /// Replaces the base samples directory, which is searched after all local directories.
+    /// If not set, the current samples path of the context is used as the base.
+    pub fn samples_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.samples.base = Some(path.into());
+        self
+    }
+
+    /// Adds a directory with local samples, which is searched before the base samples path.
+    /// Directories added earlier are searched first.
+    pub fn local_samples_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.samples.local.push(path.into());
+        self
+    }
+
+    /// Applies the configuration to the default context.
+    pub fn apply_to_default(&self) -> Result<(), ContextConfigError> {
+        unsafe { self.apply(codes_context_get_default()) }
+    }
+
+    /// Applies the configuration to `context`.
+    ///
+    /// All directories are validated before any path is changed,
+    /// so the context is left untouched if an error is returned.
+    ///
+    /// # Safety
+    ///
+    /// `context` must be a valid pointer to `codes_context`.
+    pub unsafe fn apply(&self, context: *mut codes_context) -> Result<(), ContextConfigError> {
+        crate::version::guard();
+
+        self.definitions.validate()?;
+        self.samples.validate()?;
+
+        let _lock = crate::sync::CONTEXT.lock();
+
+        unsafe {
+            if let Some(path) = self.definitions.join(codes_definition_path(context)) {
+                codes_context_set_definitions_path(context, path.as_ptr());
+            }
+
+            if let Some(path) = self.samples.join(codes_samples_path(context)) {
+                codes_context_set_samples_path(context, path.as_ptr());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PathList {
+    fn validate(&self) -> Result<(), ContextConfigError> {
+        for path in self.local.iter().chain(&self.base) {
+            if !path.is_dir() {
+                return Err(ContextConfigError::NotADirectory(path.clone()));
+            }
+
+            let bytes = path.as_os_str().as_encoded_bytes();
+            if bytes.contains(&b':') || bytes.contains(&0) {
+                return Err(ContextConfigError::InvalidPath(path.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Joins local directories and the base into a path list,
+    /// with `current` path of the context as the base if no base has been set.
+    /// Returns `None` if the path does not change.
+    unsafe fn join(&self, current: *const c_char) -> Option<CString> {
+        if self.local.is_empty() && self.base.is_none() {
+            return None;
+        }
+
+        let mut list = Vec::new();
+
+        for path in &self.local {
+            list.extend_from_slice(path.as_os_str().as_encoded_bytes());
+            list.push(b':');
+        }
+
+        match &self.base {
+            Some(base) => list.extend_from_slice(base.as_os_str().as_encoded_bytes()),
+            None if !current.is_null() => {
+                list.extend_from_slice(unsafe { CStr::from_ptr(current) }.to_bytes())
+            }
+            None => {
+                list.pop();
+            }
+        }
+
+        // nul bytes have been rejected by validate and C strings cannot contain them
+        Some(CString::new(list).expect("path list contains a nul byte"))
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/default_paths.rs"));
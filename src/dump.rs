@@ -0,0 +1,173 @@
+//! Dumping message content into memory instead of a C `FILE`.
+//!
+//! `codes_dump_content` writes to a `*mut FILE`, so helpers in this module pass it a stream
+//! created with `open_memstream` and copy the output into a [`String`] or any [`Write`].
+
+use std::{
+    ffi::CStr,
+    io::{self, Write},
+    ops::{BitOr, BitOrAssign},
+    os::raw::{c_char, c_ulong},
+    ptr, slice,
+};
+
+use crate::*;
+
+/// Format of the dump, passed as `mode` to `codes_dump_content`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum DumpMode {
+    /// `default` - keys with their values and short descriptions, as printed by `grib_dump`.
+    #[default]
+    Default,
+    /// `wmo` - keys listed by octets of the message, as in WMO documentation.
+    Wmo,
+    /// `json` - keys and values as a JSON document.
+    Json,
+    /// `serialize` - `key = value` lines.
+    Serialize,
+    /// `debug` - internal representation of all keys, useful for debugging definitions.
+    Debug,
+}
+
+impl DumpMode {
+    /// Returns the name of the mode as accepted by ecCodes.
+    pub fn as_c_str(self) -> &'static CStr {
+        match self {
+            DumpMode::Default => c"default",
+            DumpMode::Wmo => c"wmo",
+            DumpMode::Json => c"json",
+            DumpMode::Serialize => c"serialize",
+            DumpMode::Debug => c"debug",
+        }
+    }
+}
+
+/// Set of `CODES_DUMP_FLAG_*` flags controlling what is included in the dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DumpFlags(c_ulong);
+
+impl DumpFlags {
+    /// `CODES_DUMP_FLAG_READ_ONLY` - include read-only keys.
+    pub const READ_ONLY: Self = Self(CODES_DUMP_FLAG_READ_ONLY as c_ulong);
+    /// `CODES_DUMP_FLAG_DUMP_OK` - include only keys that can be dumped.
+    pub const DUMP_OK: Self = Self(CODES_DUMP_FLAG_DUMP_OK as c_ulong);
+    /// `CODES_DUMP_FLAG_VALUES` - include data values.
+    pub const VALUES: Self = Self(CODES_DUMP_FLAG_VALUES as c_ulong);
+    /// `CODES_DUMP_FLAG_CODED` - include coded keys.
+    pub const CODED: Self = Self(CODES_DUMP_FLAG_CODED as c_ulong);
+    /// `CODES_DUMP_FLAG_OCTET` - include octet positions.
+    pub const OCTET: Self = Self(CODES_DUMP_FLAG_OCTET as c_ulong);
+    /// `CODES_DUMP_FLAG_ALIASES` - include aliases of keys.
+    pub const ALIASES: Self = Self(CODES_DUMP_FLAG_ALIASES as c_ulong);
+    /// `CODES_DUMP_FLAG_TYPE` - include types of keys.
+    pub const TYPE: Self = Self(CODES_DUMP_FLAG_TYPE as c_ulong);
+    /// `CODES_DUMP_FLAG_HEXADECIMAL` - print octets in hexadecimal.
+    pub const HEXADECIMAL: Self = Self(CODES_DUMP_FLAG_HEXADECIMAL as c_ulong);
+    /// `CODES_DUMP_FLAG_NO_DATA` - omit data section.
+    pub const NO_DATA: Self = Self(CODES_DUMP_FLAG_NO_DATA as c_ulong);
+    /// `CODES_DUMP_FLAG_ALL_DATA` - print all data values instead of a sample.
+    pub const ALL_DATA: Self = Self(CODES_DUMP_FLAG_ALL_DATA as c_ulong);
+    /// `CODES_DUMP_FLAG_ALL_ATTRIBUTES` - include all attributes of BUFR keys.
+    pub const ALL_ATTRIBUTES: Self = Self(CODES_DUMP_FLAG_ALL_ATTRIBUTES as c_ulong);
+
+    /// Returns an empty set of flags.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Creates a set from raw flag bits.
+    pub const fn from_bits(bits: c_ulong) -> Self {
+        Self(bits)
+    }
+
+    /// Returns raw flag bits as passed to ecCodes.
+    pub const fn bits(self) -> c_ulong {
+        self.0
+    }
+
+    /// Checks whether all flags of `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for DumpFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for DumpFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Dumps content of the message in `handle` into `writer`.
+///
+/// # Safety
+///
+/// `handle` must be a valid pointer to `codes_handle`.
+pub unsafe fn dump_to_writer<W: Write + ?Sized>(
+    handle: *const codes_handle,
+    mode: DumpMode,
+    flags: DumpFlags,
+    writer: &mut W,
+) -> io::Result<()> {
+    crate::version::guard();
+
+    let mut buffer: *mut c_char = ptr::null_mut();
+    let mut size = 0;
+
+    unsafe {
+        let stream = libc::open_memstream(&mut buffer, &mut size);
+        if stream.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+
+        codes_dump_content(
+            handle,
+            stream,
+            mode.as_c_str().as_ptr(),
+            flags.bits(),
+            ptr::null_mut(),
+        );
+
+        // buffer and size are valid only after the stream is closed
+        let closed = libc::fclose(stream);
+
+        let result = if closed != 0 {
+            Err(io::Error::last_os_error())
+        } else if buffer.is_null() {
+            Ok(())
+        } else {
+            writer.write_all(slice::from_raw_parts(buffer.cast::<u8>(), size))
+        };
+
+        libc::free(buffer.cast());
+        result
+    }
+}
+
+/// Dumps content of the message in `handle` into a [`String`].
+/// Bytes that are not valid UTF-8 are replaced with `U+FFFD`.
+///
+/// # Safety
+///
+/// `handle` must be a valid pointer to `codes_handle`.
+pub unsafe fn dump_to_string(
+    handle: *const codes_handle,
+    mode: DumpMode,
+    flags: DumpFlags,
+) -> io::Result<String> {
+    let mut output = Vec::new();
+    unsafe { dump_to_writer(handle, mode, flags, &mut output)? };
+
+    Ok(match String::from_utf8(output) {
+        Ok(output) => output,
+        Err(err) => String::from_utf8_lossy(err.as_bytes()).into_owned(),
+    })
+}
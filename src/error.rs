@@ -0,0 +1,207 @@
+//! Typed representation of error codes returned by ecCodes functions.
+
+use std::{
+    error::Error,
+    ffi::{CStr, c_int},
+    fmt,
+};
+
+use crate::*;
+
+macro_rules! codes_errors {
+    ($($(#[$meta:meta])* $variant:ident = $code:ident,)*) => {
+        /// Error returned by ecCodes functions.
+        ///
+        /// Each variant corresponds to one of `GRIB_*` error constants
+        /// (and its `CODES_*` alias) defined in ecCodes headers.
+        /// Variants of codes added in newer ecCodes versions are available
+        /// only if headers of the linked ecCodes define them.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum CodesError {
+            $(
+                $(#[$meta])*
+                #[doc = concat!("`", stringify!($code), "`")]
+                $variant,
+            )*
+            /// Error code not known to this crate, eg. added in ecCodes newer than the bindings
+            Unknown(c_int),
+        }
+
+        impl CodesError {
+            /// Returns the ecCodes error code of this error.
+            pub fn code(self) -> c_int {
+                match self {
+                    $($(#[$meta])* Self::$variant => $code,)*
+                    Self::Unknown(code) => code,
+                }
+            }
+
+            /// Checks whether the code has its own variant.
+            const fn is_known_code(code: c_int) -> bool {
+                match code {
+                    $($(#[$meta])* $code => true,)*
+                    _ => false,
+                }
+            }
+        }
+
+        impl TryFrom<c_int> for CodesError {
+            type Error = c_int;
+
+            /// Converts ecCodes error code into [`CodesError`].
+            /// Negative codes without own variant are converted to [`CodesError::Unknown`].
+            /// Returns the code back if it is not an error (eg. `CODES_SUCCESS`).
+            fn try_from(code: c_int) -> Result<Self, Self::Error> {
+                match code {
+                    $($(#[$meta])* $code => Ok(Self::$variant),)*
+                    code if code < 0 => Ok(Self::Unknown(code)),
+                    _ => Err(code),
+                }
+            }
+        }
+    };
+}
+
+// codes added after the minimum supported ecCodes version are gated
+// on cfgs emitted by the build script for each code defined in headers
+codes_errors! {
+    EndOfFile = GRIB_END_OF_FILE,
+    InternalError = GRIB_INTERNAL_ERROR,
+    BufferTooSmall = GRIB_BUFFER_TOO_SMALL,
+    NotImplemented = GRIB_NOT_IMPLEMENTED,
+    Code7777NotFound = GRIB_7777_NOT_FOUND,
+    ArrayTooSmall = GRIB_ARRAY_TOO_SMALL,
+    FileNotFound = GRIB_FILE_NOT_FOUND,
+    CodeNotFoundInTable = GRIB_CODE_NOT_FOUND_IN_TABLE,
+    WrongArraySize = GRIB_WRONG_ARRAY_SIZE,
+    NotFound = GRIB_NOT_FOUND,
+    IoProblem = GRIB_IO_PROBLEM,
+    InvalidMessage = GRIB_INVALID_MESSAGE,
+    DecodingError = GRIB_DECODING_ERROR,
+    EncodingError = GRIB_ENCODING_ERROR,
+    NoMoreInSet = GRIB_NO_MORE_IN_SET,
+    GeocalculusProblem = GRIB_GEOCALCULUS_PROBLEM,
+    OutOfMemory = GRIB_OUT_OF_MEMORY,
+    ReadOnly = GRIB_READ_ONLY,
+    InvalidArgument = GRIB_INVALID_ARGUMENT,
+    NullHandle = GRIB_NULL_HANDLE,
+    InvalidSectionNumber = GRIB_INVALID_SECTION_NUMBER,
+    ValueCannotBeMissing = GRIB_VALUE_CANNOT_BE_MISSING,
+    WrongLength = GRIB_WRONG_LENGTH,
+    InvalidType = GRIB_INVALID_TYPE,
+    WrongStep = GRIB_WRONG_STEP,
+    WrongStepUnit = GRIB_WRONG_STEP_UNIT,
+    InvalidFile = GRIB_INVALID_FILE,
+    InvalidGrib = GRIB_INVALID_GRIB,
+    InvalidIndex = GRIB_INVALID_INDEX,
+    InvalidIterator = GRIB_INVALID_ITERATOR,
+    InvalidKeysIterator = GRIB_INVALID_KEYS_ITERATOR,
+    InvalidNearest = GRIB_INVALID_NEAREST,
+    InvalidOrderby = GRIB_INVALID_ORDERBY,
+    MissingKey = GRIB_MISSING_KEY,
+    OutOfArea = GRIB_OUT_OF_AREA,
+    ConceptNoMatch = GRIB_CONCEPT_NO_MATCH,
+    HashArrayNoMatch = GRIB_HASH_ARRAY_NO_MATCH,
+    NoDefinitions = GRIB_NO_DEFINITIONS,
+    WrongType = GRIB_WRONG_TYPE,
+    End = GRIB_END,
+    NoValues = GRIB_NO_VALUES,
+    WrongGrid = GRIB_WRONG_GRID,
+    EndOfIndex = GRIB_END_OF_INDEX,
+    NullIndex = GRIB_NULL_INDEX,
+    PrematureEndOfFile = GRIB_PREMATURE_END_OF_FILE,
+    InternalArrayTooSmall = GRIB_INTERNAL_ARRAY_TOO_SMALL,
+    MessageTooLarge = GRIB_MESSAGE_TOO_LARGE,
+    ConstantField = GRIB_CONSTANT_FIELD,
+    SwitchNoMatch = GRIB_SWITCH_NO_MATCH,
+    Underflow = GRIB_UNDERFLOW,
+    MessageMalformed = GRIB_MESSAGE_MALFORMED,
+    CorruptedIndex = GRIB_CORRUPTED_INDEX,
+    InvalidBpv = GRIB_INVALID_BPV,
+    DifferentEdition = GRIB_DIFFERENT_EDITION,
+    ValueDifferent = GRIB_VALUE_DIFFERENT,
+    InvalidKeyValue = GRIB_INVALID_KEY_VALUE,
+    StringTooSmall = GRIB_STRING_TOO_SMALL,
+    WrongConversion = GRIB_WRONG_CONVERSION,
+    MissingBufrEntry = GRIB_MISSING_BUFR_ENTRY,
+    NullPointer = GRIB_NULL_POINTER,
+    AttributeClash = GRIB_ATTRIBUTE_CLASH,
+    TooManyAttributes = GRIB_TOO_MANY_ATTRIBUTES,
+    AttributeNotFound = GRIB_ATTRIBUTE_NOT_FOUND,
+    UnsupportedEdition = GRIB_UNSUPPORTED_EDITION,
+    OutOfRange = GRIB_OUT_OF_RANGE,
+    WrongBitmapSize = GRIB_WRONG_BITMAP_SIZE,
+    FunctionalityNotEnabled = GRIB_FUNCTIONALITY_NOT_ENABLED,
+    #[cfg(eccodes_error = "GRIB_VALUE_MISMATCH")]
+    ValueMismatch = GRIB_VALUE_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_DOUBLE_VALUE_MISMATCH")]
+    DoubleValueMismatch = GRIB_DOUBLE_VALUE_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_LONG_VALUE_MISMATCH")]
+    LongValueMismatch = GRIB_LONG_VALUE_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_BYTE_VALUE_MISMATCH")]
+    ByteValueMismatch = GRIB_BYTE_VALUE_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_STRING_VALUE_MISMATCH")]
+    StringValueMismatch = GRIB_STRING_VALUE_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_OFFSET_MISMATCH")]
+    OffsetMismatch = GRIB_OFFSET_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_COUNT_MISMATCH")]
+    CountMismatch = GRIB_COUNT_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_NAME_MISMATCH")]
+    NameMismatch = GRIB_NAME_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_TYPE_MISMATCH")]
+    TypeMismatch = GRIB_TYPE_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_TYPE_AND_VALUE_MISMATCH")]
+    TypeAndValueMismatch = GRIB_TYPE_AND_VALUE_MISMATCH,
+    #[cfg(eccodes_error = "GRIB_UNABLE_TO_COMPARE_ACCESSORS")]
+    UnableToCompareAccessors = GRIB_UNABLE_TO_COMPARE_ACCESSORS,
+    #[cfg(eccodes_error = "GRIB_ASSERTION_FAILURE")]
+    AssertionFailure = GRIB_ASSERTION_FAILURE,
+}
+
+/// Converts a value returned by ecCodes function into [`Result`].
+///
+/// `CODES_SUCCESS` is converted to `Ok(())`, error codes to the matching [`CodesError`].
+///
+/// If an assertion failure has been recorded on the current thread since the previous call,
+/// [`CodesError::AssertionFailure`] is returned instead (see [`assertion`]).
+///
+/// With the `runtime-version-check` feature the first call also verifies
+/// that ecCodes loaded at runtime matches the bindings and panics if it does not.
+pub fn check(ret: c_int) -> Result<(), CodesError> {
+    crate::version::guard();
+
+    #[cfg(eccodes_error = "GRIB_ASSERTION_FAILURE")]
+    if crate::assertion::report_failure() {
+        return Err(CodesError::AssertionFailure);
+    }
+
+    if ret == CODES_SUCCESS as c_int {
+        return Ok(());
+    }
+
+    Err(CodesError::try_from(ret).unwrap_or(CodesError::Unknown(ret)))
+}
+
+impl fmt::Display for CodesError {
+    /// Writes the message provided by `codes_get_error_message`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = unsafe { codes_get_error_message(self.code()) };
+
+        if message.is_null() {
+            return write!(f, "ecCodes error {}", self.code());
+        }
+
+        let message = unsafe { CStr::from_ptr(message) };
+        write!(
+            f,
+            "{} (ecCodes error {})",
+            message.to_string_lossy(),
+            self.code()
+        )
+    }
+}
+
+impl Error for CodesError {}
+
+include!(concat!(env!("OUT_DIR"), "/error_codes.rs"));
@@ -0,0 +1,351 @@
+//! Fast extraction of message information from files without creating handles.
+//!
+//! Functions in this module wrap `codes_extract_offsets_sizes_malloc` and `codes_bufr_extract_headers_malloc`,
+//! copying the arrays they return into Rust collections and releasing them.
+//! [`extract_offsets`] requires ecCodes 2.34 or newer.
+//!
+//! With the `chrono` feature, dates in [`BufrHeader`] can be converted into `chrono` types.
+
+use std::{
+    ffi::CString,
+    os::raw::{c_char, c_int, c_void},
+    path::Path,
+    ptr, slice,
+};
+
+use crate::{
+    error::{CodesError, check},
+    *,
+};
+
+/// Returns offsets and sizes in bytes of all messages of `product` kind in the file at `path`.
+///
+/// In `strict` mode any data between messages is treated as an error,
+/// otherwise it is skipped.
+///
+/// Available with ecCodes 2.34 and newer.
+#[cfg(eccodes_2_34)]
+pub fn extract_offsets(
+    path: impl AsRef<Path>,
+    product: ProductKind,
+    strict: bool,
+) -> Result<Vec<(i64, usize)>, CodesError> {
+    let path = path_to_cstring(path.as_ref())?;
+
+    let mut offsets: *mut off_t = ptr::null_mut();
+    let mut sizes: *mut usize = ptr::null_mut();
+    let mut num_messages: c_int = 0;
+
+    let ret = {
+        let _lock = crate::sync::CONTEXT.lock();
+
+        unsafe {
+            codes_extract_offsets_sizes_malloc(
+                codes_context_get_default(),
+                path.as_ptr(),
+                product,
+                &mut offsets,
+                &mut sizes,
+                &mut num_messages,
+                c_int::from(strict),
+            )
+        }
+    };
+
+    let messages = unsafe {
+        let messages = if ret == CODES_SUCCESS as c_int && !offsets.is_null() && !sizes.is_null() {
+            let count = usize::try_from(num_messages).unwrap_or(0);
+            let offsets = slice::from_raw_parts(offsets, count);
+            let sizes = slice::from_raw_parts(sizes, count);
+
+            // off_t is narrower than i64 on some targets
+            #[allow(clippy::useless_conversion)]
+            offsets
+                .iter()
+                .zip(sizes)
+                .map(|(&offset, &size)| (i64::from(offset), size))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // arrays are allocated with calloc, not with memory procedures of the context
+        free(offsets.cast());
+        free(sizes.cast());
+
+        messages
+    };
+
+    check(ret)?;
+
+    Ok(messages)
+}
+
+/// Releases an array allocated by ecCodes with C `malloc` or `calloc`.
+unsafe fn free(array: *mut c_void) {
+    if !array.is_null() {
+        unsafe { libc::free(array) };
+    }
+}
+
+/// Converts `path` into a C string, failing if it contains a nul byte.
+fn path_to_cstring(path: &Path) -> Result<CString, CodesError> {
+    #[cfg(unix)]
+    let bytes = {
+        use std::os::unix::ffi::OsStrExt;
+        path.as_os_str().as_bytes().to_vec()
+    };
+
+    #[cfg(not(unix))]
+    let bytes = path
+        .to_str()
+        .ok_or(CodesError::InvalidArgument)?
+        .as_bytes()
+        .to_vec();
+
+    CString::new(bytes).map_err(|_| CodesError::InvalidArgument)
+}
+
+/// Date and time stored in BUFR header as separate fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufrDateTime {
+    pub year: i64,
+    pub month: i64,
+    pub day: i64,
+    pub hour: i64,
+    pub minute: i64,
+    pub second: i64,
+}
+
+/// Day of month and time stored in the ECMWF local section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufrDayTime {
+    pub day: i64,
+    pub hour: i64,
+    pub minute: i64,
+    pub second: i64,
+}
+
+/// Observation described by the ECMWF local section, depending on whether it comes from a satellite.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BufrLocalObservation {
+    /// Observation from a station identified by `ident`, at a single location.
+    Station {
+        ident: String,
+        latitude: f64,
+        longitude: f64,
+    },
+    /// Satellite observation covering an area between two corners.
+    Satellite {
+        satellite_id: i64,
+        latitude1: f64,
+        longitude1: f64,
+        latitude2: f64,
+        longitude2: f64,
+    },
+}
+
+/// Fields of the ECMWF local section of BUFR message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BufrEcmwfLocalSection {
+    pub rdb_type: i64,
+    pub old_subtype: i64,
+    pub rdb_subtype: i64,
+    pub new_subtype: i64,
+    pub local_time: BufrDateTime,
+    pub rdb_time: BufrDayTime,
+    pub rec_time: BufrDayTime,
+    pub restricted: bool,
+    pub number_of_observations: i64,
+    pub quality_control: i64,
+    pub da_loop: i64,
+    pub observation: BufrLocalObservation,
+}
+
+/// Header of BUFR message extracted with `codes_bufr_extract_headers_malloc`.
+///
+/// Field names follow ecCodes keys, eg. `data_category` is `dataCategory`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BufrHeader {
+    pub message_offset: u64,
+    pub message_size: u64,
+    pub edition: i64,
+    pub master_table_number: i64,
+    pub centre: i64,
+    pub sub_centre: i64,
+    pub update_sequence_number: i64,
+    pub data_category: i64,
+    pub data_sub_category: i64,
+    pub international_data_sub_category: i64,
+    pub master_tables_version_number: i64,
+    pub local_tables_version_number: i64,
+    pub typical_time: BufrDateTime,
+    pub local_section_present: bool,
+    /// `None` if the message has no local section or it is not in the ECMWF format.
+    pub ecmwf_local_section: Option<BufrEcmwfLocalSection>,
+    pub number_of_subsets: u64,
+    pub observed_data: bool,
+    pub compressed_data: bool,
+}
+
+#[cfg(feature = "chrono")]
+impl BufrDateTime {
+    /// Converts into [`chrono::NaiveDateTime`], returns `None` if the date or time is invalid.
+    pub fn to_naive_datetime(&self) -> Option<chrono::NaiveDateTime> {
+        let date = chrono::NaiveDate::from_ymd_opt(
+            i32::try_from(self.year).ok()?,
+            u32::try_from(self.month).ok()?,
+            u32::try_from(self.day).ok()?,
+        )?;
+
+        date.and_hms_opt(
+            u32::try_from(self.hour).ok()?,
+            u32::try_from(self.minute).ok()?,
+            u32::try_from(self.second).ok()?,
+        )
+    }
+}
+
+// c_long and c_ulong are 32-bit on some targets
+#[allow(clippy::unnecessary_cast)]
+impl From<&codes_bufr_header> for BufrHeader {
+    fn from(h: &codes_bufr_header) -> Self {
+        let ecmwf_local_section = (h.ecmwfLocalSectionPresent != 0).then(|| {
+            let observation = if h.isSatellite != 0 {
+                BufrLocalObservation::Satellite {
+                    satellite_id: h.satelliteID as i64,
+                    latitude1: h.localLatitude1,
+                    longitude1: h.localLongitude1,
+                    latitude2: h.localLatitude2,
+                    longitude2: h.localLongitude2,
+                }
+            } else {
+                BufrLocalObservation::Station {
+                    ident: ident_to_string(&h.ident),
+                    latitude: h.localLatitude,
+                    longitude: h.localLongitude,
+                }
+            };
+
+            BufrEcmwfLocalSection {
+                rdb_type: h.rdbType as i64,
+                old_subtype: h.oldSubtype as i64,
+                rdb_subtype: h.rdbSubtype as i64,
+                new_subtype: h.newSubtype as i64,
+                local_time: BufrDateTime {
+                    year: h.localYear as i64,
+                    month: h.localMonth as i64,
+                    day: h.localDay as i64,
+                    hour: h.localHour as i64,
+                    minute: h.localMinute as i64,
+                    second: h.localSecond as i64,
+                },
+                rdb_time: BufrDayTime {
+                    day: h.rdbtimeDay as i64,
+                    hour: h.rdbtimeHour as i64,
+                    minute: h.rdbtimeMinute as i64,
+                    second: h.rdbtimeSecond as i64,
+                },
+                rec_time: BufrDayTime {
+                    day: h.rectimeDay as i64,
+                    hour: h.rectimeHour as i64,
+                    minute: h.rectimeMinute as i64,
+                    second: h.rectimeSecond as i64,
+                },
+                restricted: h.restricted != 0,
+                number_of_observations: h.localNumberOfObservations as i64,
+                quality_control: h.qualityControl as i64,
+                da_loop: h.daLoop as i64,
+                observation,
+            }
+        });
+
+        BufrHeader {
+            message_offset: h.message_offset as u64,
+            message_size: h.message_size as u64,
+            edition: h.edition as i64,
+            master_table_number: h.masterTableNumber as i64,
+            centre: h.bufrHeaderCentre as i64,
+            sub_centre: h.bufrHeaderSubCentre as i64,
+            update_sequence_number: h.updateSequenceNumber as i64,
+            data_category: h.dataCategory as i64,
+            data_sub_category: h.dataSubCategory as i64,
+            international_data_sub_category: h.internationalDataSubCategory as i64,
+            master_tables_version_number: h.masterTablesVersionNumber as i64,
+            local_tables_version_number: h.localTablesVersionNumber as i64,
+            typical_time: BufrDateTime {
+                year: h.typicalYear as i64,
+                month: h.typicalMonth as i64,
+                day: h.typicalDay as i64,
+                hour: h.typicalHour as i64,
+                minute: h.typicalMinute as i64,
+                second: h.typicalSecond as i64,
+            },
+            local_section_present: h.localSectionPresent != 0,
+            ecmwf_local_section,
+            number_of_subsets: h.numberOfSubsets as u64,
+            observed_data: h.observedData != 0,
+            compressed_data: h.compressedData != 0,
+        }
+    }
+}
+
+/// Returns headers of all BUFR messages in the file at `path`, without decoding their data.
+///
+/// In `strict` mode any data between messages is treated as an error,
+/// otherwise it is skipped.
+pub fn extract_bufr_headers(
+    path: impl AsRef<Path>,
+    strict: bool,
+) -> Result<Vec<BufrHeader>, CodesError> {
+    let path = path_to_cstring(path.as_ref())?;
+
+    let mut headers: *mut codes_bufr_header = ptr::null_mut();
+    let mut num_messages: c_int = 0;
+
+    let ret = {
+        let _lock = crate::sync::CONTEXT.lock();
+
+        unsafe {
+            codes_bufr_extract_headers_malloc(
+                codes_context_get_default(),
+                path.as_ptr(),
+                &mut headers,
+                &mut num_messages,
+                c_int::from(strict),
+            )
+        }
+    };
+
+    let messages = unsafe {
+        let messages = if ret == CODES_SUCCESS as c_int && !headers.is_null() {
+            let count = usize::try_from(num_messages).unwrap_or(0);
+            slice::from_raw_parts(headers, count)
+                .iter()
+                .map(BufrHeader::from)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // array is allocated with calloc, not with memory procedures of the context
+        free(headers.cast());
+
+        messages
+    };
+
+    check(ret)?;
+
+    Ok(messages)
+}
+
+/// Converts station identifier, which is nul-terminated if shorter than the array, into [`String`].
+fn ident_to_string(ident: &[c_char]) -> String {
+    let bytes = ident
+        .iter()
+        .map(|&c| c as u8)
+        .take_while(|&b| b != 0)
+        .collect::<Vec<_>>();
+
+    String::from_utf8_lossy(&bytes).trim().to_string()
+}
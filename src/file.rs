@@ -0,0 +1,138 @@
+//! C `FILE` streams for ecCodes functions reading and writing files.
+//!
+//! Functions such as `codes_handle_new_from_file`, `codes_count_in_file` or `wmo_read_any_from_file`
+//! take `*mut FILE`. [`CFile`] opens such a stream from a path, a [`File`] or a byte buffer
+//! and closes it when dropped.
+
+use std::{
+    ffi::{CStr, CString},
+    fs::File,
+    io,
+    marker::PhantomData,
+    os::unix::{ffi::OsStrExt, io::AsRawFd},
+    path::Path,
+    ptr::NonNull,
+};
+
+use crate::*;
+
+/// Owned C `FILE` stream, closed with `fclose` when dropped.
+///
+/// The lifetime ties the stream to the buffer it reads from when created with [`CFile::from_buffer`].
+#[derive(Debug)]
+pub struct CFile<'a> {
+    stream: NonNull<FILE>,
+    _buffer: PhantomData<&'a [u8]>,
+}
+
+impl CFile<'static> {
+    /// Opens the file at `path` for reading.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::open_with_mode(path.as_ref(), c"rb")
+    }
+
+    /// Creates or truncates the file at `path` and opens it for writing.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::open_with_mode(path.as_ref(), c"wb")
+    }
+
+    fn open_with_mode(path: &Path, mode: &CStr) -> io::Result<Self> {
+        let path = CString::new(path.as_os_str().as_bytes())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+
+        unsafe { Self::from_stream(libc::fopen(path.as_ptr(), mode.as_ptr())) }
+    }
+
+    /// Opens a stream on a duplicate of the descriptor of `file`, with the same access mode.
+    ///
+    /// The stream shares the file position with `file`, but it is buffered,
+    /// so the position of `file` is unspecified while the stream is open.
+    pub fn from_file(file: &File) -> io::Result<Self> {
+        unsafe {
+            let flags = libc::fcntl(file.as_raw_fd(), libc::F_GETFL);
+            if flags == -1 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let mode = match flags & libc::O_ACCMODE {
+                libc::O_WRONLY if flags & libc::O_APPEND != 0 => c"ab",
+                libc::O_WRONLY => c"wb",
+                libc::O_RDWR if flags & libc::O_APPEND != 0 => c"a+b",
+                libc::O_RDWR => c"r+b",
+                _ => c"rb",
+            };
+
+            let fd = libc::dup(file.as_raw_fd());
+            if fd == -1 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let stream = libc::fdopen(fd, mode.as_ptr());
+            if stream.is_null() {
+                let err = io::Error::last_os_error();
+                libc::close(fd);
+                return Err(err);
+            }
+
+            Self::from_stream(stream)
+        }
+    }
+}
+
+impl<'a> CFile<'a> {
+    /// Opens a read-only stream over `buffer` with `fmemopen`.
+    pub fn from_buffer(buffer: &'a [u8]) -> io::Result<Self> {
+        // fmemopen rejects empty buffers on some platforms
+        if buffer.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot open stream over an empty buffer",
+            ));
+        }
+
+        // the buffer is not modified in read mode
+        unsafe {
+            Self::from_stream(libc::fmemopen(
+                buffer.as_ptr().cast_mut().cast(),
+                buffer.len(),
+                c"rb".as_ptr(),
+            ))
+        }
+    }
+
+    /// Takes ownership of `stream` returned by a function setting `errno` on failure.
+    unsafe fn from_stream(stream: *mut FILE) -> io::Result<Self> {
+        match NonNull::new(stream) {
+            Some(stream) => Ok(CFile {
+                stream,
+                _buffer: PhantomData,
+            }),
+            None => Err(io::Error::last_os_error()),
+        }
+    }
+
+    /// Returns the stream to pass to ecCodes functions.
+    /// The pointer is valid as long as `self` is not dropped.
+    pub fn as_ptr(&self) -> *mut FILE {
+        self.stream.as_ptr()
+    }
+
+    /// Flushes and closes the stream, returning an error that is ignored when the stream is dropped.
+    pub fn close(self) -> io::Result<()> {
+        let stream = self.stream.as_ptr();
+        std::mem::forget(self);
+
+        match unsafe { libc::fclose(stream) } {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+}
+
+impl Drop for CFile<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            libc::fclose(self.stream.as_ptr());
+        }
+    }
+}
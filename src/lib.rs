@@ -10,8 +10,9 @@
 //!**This is a `-sys` crate with raw, unsafe bindings to the library and its API should not be used directly.** 
 //!See the [eccodes crate](https://github.com/ScaleWeather/eccodes) for high-level, safe bindings.
 //!
-//!**Due to the complexity of ecCodes library the decision has been made that this crate will not build ecCodes from source.**
-//!See sections below for additional information how to install ecCodes on your system.
+//!**Due to the complexity of ecCodes library by default this crate will not build ecCodes from source.**
+//!See sections below for additional information how to install ecCodes on your system
+//!or how to opt-in into building ecCodes from source with the `vendored` feature.
 //!
 //![ecCodes](https://confluence.ecmwf.int/display/ECC/ecCodes+Home) is an open-source library for 
 //!reading and writing GRIB and BUFR files developed by [European Centre for Medium-Range Weather Forecasts](https://www.ecmwf.int/).
@@ -19,7 +20,12 @@
 //!## Usage
 //!
 //!This crate will look for existing `libeccodes` installation using [pkg-config](https://crates.io/crates/pkg-config).
-//!The ecCodes library is then linked and bindings are generated using [bindgen](https://crates.io/crates/bindgen). 
+//!The ecCodes library is then linked and checked-in bindings matching its minor version
+//!(currently ecCodes 2.34 on 64-bit Unix targets) are used, so libclang is not needed to build this crate.
+//!For other versions and targets the build fails unless the `bindgen` feature is enabled,
+//!which generates bindings at build time using [bindgen](https://crates.io/crates/bindgen) and requires libclang.
+//!Bindings contain only ecCodes API (`codes_*`, `grib_*`, `wmo_*`, `bufr_*` items and `CODES_*`, `GRIB_*`, `ECCODES_*` constants),
+//!C types `FILE` and `off_t` used in ecCodes functions are re-exported from the [libc](https://crates.io/crates/libc) crate.
 //!If the library is not found, the build will fail. 
 //!
 //!## ecCodes installation
@@ -45,18 +51,190 @@
 //!```
 //!
 //!
+//!## Custom ecCodes location
+//!
+//!If ecCodes has been installed without a pkg-config file (eg. loaded as a module on HPC clusters),
+//!its location can be set explicitly with environmental variables, which take precedence over pkg-config:
+//!
+//!- `ECCODES_DIR` - installation prefix, containing `include` and `lib` (or `lib64`) directories
+//!- `ECCODES_INCLUDE_DIR` and `ECCODES_LIB_DIR` - directories with headers and the library,
+//!  overriding the ones derived from `ECCODES_DIR` (both are required if `ECCODES_DIR` is not set)
+//!
+//!```text
+//!export ECCODES_DIR=<your_eccodes_path>
+//!```
+//!
+//!When none of these variables is set and pkg-config cannot find ecCodes, the crate looks for
+//!`eccodes-config.cmake` in the `eccodes_DIR` directory and under prefixes listed in `CMAKE_PREFIX_PATH`.
+//!
+//!## Static linking
+//!
+//!By default the installed ecCodes is linked dynamically, so it must also be available at runtime.
+//!To link `libeccodes.a` statically enable the `static` feature or set the `ECCODES_STATIC` environmental variable:
+//!
+//!```text
+//!export ECCODES_STATIC=1
+//!```
+//!
+//!In static mode pkg-config is queried for private dependencies of ecCodes (eg. openjpeg, libaec, libpng, zlib),
+//!which are linked as well. The ecCodes installation must contain the static library for this mode to work.
+//!When ecCodes is found without pkg-config, its dependencies must be listed in the `ECCODES_STATIC_LIBS`
+//!environmental variable, separated with spaces or commas (an empty value if ecCodes has been built without them):
+//!
+//!```bash
+//!export ECCODES_STATIC_LIBS="aec openjp2 png z"
+//!```
+//!
+//!## Building ecCodes from source
+//!
+//!When the `vendored` feature is enabled, this crate will not look for an existing installation.
+//!Instead ecCodes is compiled with [CMake](https://crates.io/crates/cmake) from a source tree
+//!pointed to by the `ECCODES_SOURCE_DIR` environmental variable and linked statically.
+//!Bindings are generated against headers of the built library, so this feature enables `bindgen` and requires libclang.
+//!For example:
+//!
+//!```text
+//!export ECCODES_SOURCE_DIR=<path_to_unpacked_eccodes_source_tarball>
+//!cargo build --features vendored
+//!```
+//!
+//!Building requires CMake, a C/C++ compiler and [ecbuild](https://github.com/ecmwf/ecbuild) if it is not bundled with the sources.
+//!Optional components can be toggled with environmental variables set to `1` or `0`:
+//!
+//!- `ECCODES_ENABLE_JPG` - JPEG2000 packing with OpenJPEG (`libopenjp2`), off by default
+//!- `ECCODES_ENABLE_AEC` - CCSDS packing with `libaec`, off by default
+//!- `ECCODES_ENABLE_PNG` - PNG packing with `libpng` and `zlib`, off by default
+//!- `ECCODES_ENABLE_MEMFS` - definitions and samples compiled into the library, on by default (requires Python 3 to build)
+//!
+//!Libraries of enabled components must be installed in the system and are linked dynamically.
+//!Without MEMFS the library looks for definitions in the build directory of this crate,
+//!so binaries copied to another machine have to set the definitions path with [`ContextConfig`](context::ContextConfig)
+//!or the `ECCODES_DEFINITION_PATH` environmental variable.
+//!NetCDF, Fortran and Python interfaces are always disabled.
+//!
+//!## Version detection
+//!
+//!The build script detects version of ecCodes it links against and emits `eccodes_<major>_<minor>` cfg
+//!for the detected and every older minor version (eg. `eccodes_2_30` is set for ecCodes 2.30 and newer, including 3.x),
+//!which is used to gate items available only in newer ecCodes versions.
+//!
+//!The version is also exported to build scripts of dependent crates as `DEP_ECCODES_VERSION`,
+//!so they can set their own cfgs, for example to use `codes_get_float_array` only when it is available:
+//!
+//!```ignore
+//!// build.rs of a crate depending on eccodes-sys
+//!fn main() {
+//!    println!("cargo::rustc-check-cfg=cfg(eccodes_float)");
+//!
+//!    if let Ok(version) = std::env::var("DEP_ECCODES_VERSION") {
+//!        let mut parts = version.split('.').map(|p| p.parse::<u32>().unwrap_or(0));
+//!        let version = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+//!
+//!        if version >= (2, 30) {
+//!            println!("cargo::rustc-cfg=eccodes_float");
+//!        }
+//!    }
+//!}
+//!```
+//!
+//!## Build metadata
+//!
+//!Besides the version, the build script exports directories of the ecCodes installation it has resolved,
+//!so that dependent crates compiling their own C code against ecCodes use the same installation
+//!instead of probing for it again:
+//!
+//!- `DEP_ECCODES_INCLUDE` - directory containing `eccodes.h`
+//!- `DEP_ECCODES_LIB_DIR` - directory containing the ecCodes library
+//!- `DEP_ECCODES_DEFINITIONS` - default definitions directory of ecCodes, if it has been found
+//!- `DEP_ECCODES_SAMPLES` - default samples directory of ecCodes, if it has been found
+//!
+//!```ignore
+//!// build.rs of a crate with a C shim
+//!fn main() {
+//!    let include = std::env::var("DEP_ECCODES_INCLUDE").unwrap();
+//!
+//!    cc::Build::new()
+//!        .file("src/shim.c")
+//!        .include(include)
+//!        .compile("shim");
+//!}
+//!```
+//!
+//!Default directories are read from `ECCODES_DEFINITION_PATH` and `ECCODES_SAMPLES_PATH` defined in `eccodes_config.h`
+//!or, if not defined there, found in `share/eccodes` under the installation prefix. They are also available
+//!as `DEFAULT_DEFINITIONS_PATH` and `DEFAULT_SAMPLES_PATH` constants in the `context` module.
+//!
+//!When built with the `docs` feature, no installation is resolved, so these variables are not set and the constants are `None`.
+//!
+//!## Helpers
+//!
+//!Apart from raw bindings, this crate contains a thin layer of helpers shared by higher-level bindings:
+//!
+//!- [`assertion`] - recording of ecCodes assertion failures, which otherwise abort the process, and reporting them as [`AssertionFailure`](error::CodesError::AssertionFailure) error; continuing after a failure is best-effort
+//!- [`context`] - [`ContextConfig`](context::ContextConfig) builder setting validated definitions and samples paths, with local overrides searched first, on the default or given context
+//!- [`dump`] - dumping message content with `codes_dump_content` into a `String` or `impl Write` in any of ecCodes dump modes, available on Unix
+//!- [`error`] - [`CodesError`](error::CodesError) enum covering ecCodes error codes and `check` function converting return values into `Result`
+//!- [`extract`] - [`extract_offsets`](extract::extract_offsets) returning offsets and sizes of messages in a file with `codes_extract_offsets_sizes_malloc` (ecCodes 2.34 and newer) and [`extract_bufr_headers`](extract::extract_bufr_headers) returning [`BufrHeader`](extract::BufrHeader)s of BUFR messages in a file without decoding them
+//!- [`file`](mod@file) - [`CFile`](file::CFile) owning C `FILE` stream opened from a path, a `std::fs::File` or a byte buffer, for functions taking `*mut FILE`, available on Unix
+//!- `logging` - forwarding of ecCodes log messages to `log` or `tracing` crate, available with `log` or `tracing` feature
+//!- [`memory`] - opt-in routing of ecCodes allocations through the Rust global allocator, with tracking of allocated size and per-thread soft memory limits
+//!- [`raw`] - owning wrappers of ecCodes objects in [`raw::owned`], such as [`OwnedHandle`](raw::owned::OwnedHandle), which call the matching `*_delete` function on drop, and [`BorrowedHandle`](raw::borrowed::BorrowedHandle) in [`raw::borrowed`] created without copying from a buffer it borrows
+//!- [`reader`] - [`MessageReader`](reader::MessageReader) reading messages from any `std::io::Read` source with `wmo_read_any_from_stream_malloc`
+//!- [`scan`] - [`scan_messages`](scan::scan_messages) finding offsets, lengths and kinds of GRIB, BUFR, GTS, METAR and TAF messages in a byte buffer (ecCodes 2.34 and newer)
+//!- [`sync`] - lock serializing ecCodes functions that are not safe to call concurrently, shared with `CODES_LOCK`, with a table of functions requiring it; locking is a no-op when ecCodes has been built with thread support ([`THREAD_SAFE`](sync::THREAD_SAFE))
+//!- [`version`] - [`check_runtime_version`](version::check_runtime_version) comparing version of ecCodes loaded at runtime with the version bindings have been generated for
+//!
 //!## Features
 //!
+//!There are three features controlling how ecCodes is linked and bound:
+//!
+//!- `vendored` - builds ecCodes from source pointed by `ECCODES_SOURCE_DIR` and links it statically instead of using the installed library, enables `bindgen`
+//!- `static` - links the installed ecCodes statically, same as setting `ECCODES_STATIC=1`
+//!- `bindgen` - generates bindings from headers of linked ecCodes with `bindgen` instead of using pre-generated ones, requires libclang
+//!
+//!There is one feature for runtime safety checks:
+//!
+//!- `runtime-version-check` - on first use of helpers from this crate checks that ecCodes loaded at runtime has the same minor version as the bindings and panics otherwise
+//!
+//!There are four features enabling optional helpers:
+//!
+//!- `log` - enables `logging` module forwarding ecCodes messages to the `log` crate
+//!- `tracing` - enables `logging` module forwarding ecCodes messages to the `tracing` crate, takes precedence over `log` if both are enabled
+//!- `mmap` - enables `MappedFile` in `raw::borrowed`, providing `BorrowedHandle`s of messages in a memory-mapped file with `memmap2` (ecCodes 2.34 and newer)
+//!- `chrono` - enables conversion of dates in `BufrHeader` into `chrono::NaiveDateTime`
+//!
 //!There are two development features available:
 //!
 //!- `docs` - for documentation building, does not link ecCodes and includes `bindings-docs.rs` into `lib.rs`
-//!- `tests` - turns on generation of layout tests by `bindgen`, should not be used in production. Layout tests are off by default as they derefrence null pointers causing undefined behaviour
+//!- `tests` - enables `bindgen` feature and turns on generation of layout tests by `bindgen`, should not be used in production. Layout tests are off by default as they derefrence null pointers causing undefined behaviour. Also checks at compile time that every error code defined in ecCodes headers has its own `CodesError` variant
 //!
 
 use std::sync::Mutex;
 
+#[cfg(eccodes_error = "GRIB_ASSERTION_FAILURE")]
+pub mod assertion;
+pub mod context;
+#[cfg(unix)]
+pub mod dump;
+pub mod error;
+pub mod extract;
+#[cfg(unix)]
+pub mod file;
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub mod logging;
+pub mod memory;
+pub mod raw;
+pub mod reader;
+#[cfg(eccodes_2_34)]
+pub mod scan;
+pub mod sync;
+pub mod version;
+
 /// Global mutex to synchronize functions that fail in concurrent context,
 /// eg. `codes_handle_new_from_file`, `codes_index_add_file` etc.
+///
+/// This is the same mutex as the one used by [`sync::CONTEXT`].
+#[deprecated(note = "use `sync::CONTEXT` instead")]
 pub static CODES_LOCK: Mutex<()> = Mutex::new(());
 
 #[cfg(not(feature = "docs"))]
@@ -0,0 +1,74 @@
+//! Forwarding of ecCodes log messages to the `log` or `tracing` crate.
+//!
+//! By default ecCodes prints its messages to `stderr`. After calling [`install`] they are emitted
+//! as events with `eccodes` target instead, with levels mapped as follows:
+//!
+//! | ecCodes | `log`/`tracing` |
+//! |---|---|
+//! | `GRIB_LOG_FATAL` | `ERROR` |
+//! | `GRIB_LOG_ERROR` | `ERROR` |
+//! | `GRIB_LOG_WARNING` | `WARN` |
+//! | `GRIB_LOG_INFO` | `INFO` |
+//! | `GRIB_LOG_DEBUG` | `DEBUG` |
+//!
+//! Messages go to `tracing` when the `tracing` feature is enabled and to `log` otherwise.
+
+use std::{
+    ffi::CStr,
+    os::raw::{c_char, c_int},
+    panic,
+};
+
+use crate::*;
+
+/// Flag that ecCodes adds to the level of messages followed by `strerror(errno)`.
+const GRIB_LOG_PERROR: c_int = 1 << 10;
+
+/// Installs a logging procedure on the default context that forwards ecCodes messages
+/// to `log` or `tracing`. Replaces any procedure installed previously.
+pub fn install() {
+    crate::version::guard();
+    let _lock = crate::sync::CONTEXT.lock();
+
+    unsafe {
+        codes_context_set_logging_proc(codes_context_get_default(), Some(forward_message));
+    }
+}
+
+unsafe extern "C" fn forward_message(
+    _context: *const codes_context,
+    level: c_int,
+    message: *const c_char,
+) {
+    if message.is_null() {
+        return;
+    }
+
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    let message = message.trim_end();
+
+    // a panicking logger must not unwind into ecCodes
+    let _ = panic::catch_unwind(|| emit(level & !GRIB_LOG_PERROR, message));
+}
+
+#[cfg(feature = "tracing")]
+fn emit(level: c_int, message: &str) {
+    match level as u32 {
+        GRIB_LOG_FATAL | GRIB_LOG_ERROR => tracing::error!(target: "eccodes", "{}", message),
+        GRIB_LOG_WARNING => tracing::warn!(target: "eccodes", "{}", message),
+        GRIB_LOG_DEBUG => tracing::debug!(target: "eccodes", "{}", message),
+        _ => tracing::info!(target: "eccodes", "{}", message),
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+fn emit(level: c_int, message: &str) {
+    let level = match level as u32 {
+        GRIB_LOG_FATAL | GRIB_LOG_ERROR => log::Level::Error,
+        GRIB_LOG_WARNING => log::Level::Warn,
+        GRIB_LOG_DEBUG => log::Level::Debug,
+        _ => log::Level::Info,
+    };
+
+    log::log!(target: "eccodes", level, "{}", message);
+}
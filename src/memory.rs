@@ -0,0 +1,269 @@
+//! Routing of ecCodes memory allocations through the Rust global allocator.
+//!
+//! After [`install`], memory that ecCodes allocates through the default context
+//! (handles, decoded values and message buffers) comes from the
+//! [global allocator](std::alloc::GlobalAlloc), so it is visible to allocator statistics
+//! and can be measured with [`allocated`] and [`peak_allocated`].
+//!
+//! Allocations made by the current thread can be checked against a limit with [`with_limit`],
+//! which is useful to detect untrusted messages requiring too much memory. The limit is soft:
+//! ecCodes treats a refused allocation as a fatal error, so allocations over the limit
+//! still succeed and the overrun is reported after the call.
+//!
+//! Persistent memory of the context, which holds caches of definitions, and buffers returned
+//! by `*_malloc` functions, such as `wmo_read_any_from_file_malloc`, are allocated with C `malloc`
+//! and are not tracked.
+
+use std::{
+    alloc::{self, Layout},
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display},
+    os::raw::c_void,
+    ptr,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use crate::*;
+
+/// Size of the header storing allocation size in front of every block,
+/// which also keeps blocks aligned as required for C `malloc`.
+const HEADER: usize = 16;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static BUDGET: Cell<Option<Budget>> = const { Cell::new(None) };
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Budget {
+    limit: usize,
+    used: usize,
+    peak: usize,
+}
+
+/// Error returned by [`with_limit`] when ecCodes allocated more memory than allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MemoryLimitExceeded {
+    /// Limit set for the call in bytes.
+    pub limit: usize,
+    /// Highest number of bytes allocated at once during the call.
+    pub peak: usize,
+}
+
+impl Display for MemoryLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ecCodes allocated {} bytes exceeding memory limit of {} bytes",
+            self.peak, self.limit
+        )
+    }
+}
+
+impl Error for MemoryLimitExceeded {}
+
+/// Installs memory procedures of the default context that allocate with the Rust global allocator.
+/// Calling this function more than once has no additional effect.
+///
+/// Only regular and buffer memory procedures are replaced. The default context is initialised
+/// when it is first obtained, also by this function, and allocates persistent memory with C `malloc`
+/// before procedures can be replaced, so the persistent memory procedure is left unchanged.
+///
+/// # Safety
+///
+/// No handle or other ecCodes object may exist when this function is called,
+/// as memory that ecCodes allocated with C `malloc` would be released with the Rust allocator.
+/// For the same reason the default context must not be reset or deleted afterwards,
+/// since memory allocated during its initialisation may be released with the replaced procedures.
+pub unsafe fn install() {
+    crate::version::guard();
+    let _lock = crate::sync::CONTEXT.lock();
+
+    if INSTALLED.swap(true, Ordering::AcqRel) {
+        return;
+    }
+
+    unsafe {
+        let context = codes_context_get_default();
+        codes_context_set_memory_proc(context, Some(malloc), Some(free), Some(realloc));
+        codes_context_set_buffer_memory_proc(context, Some(malloc), Some(free), Some(realloc));
+    }
+}
+
+/// Checks whether memory procedures have been installed with [`install`].
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Acquire)
+}
+
+/// Returns the number of bytes currently allocated by ecCodes.
+pub fn allocated() -> usize {
+    ALLOCATED.load(Ordering::Relaxed)
+}
+
+/// Returns the highest number of bytes allocated by ecCodes at once
+/// since installation or the last [`reset_peak_allocated`].
+pub fn peak_allocated() -> usize {
+    PEAK_ALLOCATED.load(Ordering::Relaxed)
+}
+
+/// Resets the value returned by [`peak_allocated`] to the currently allocated size.
+pub fn reset_peak_allocated() {
+    PEAK_ALLOCATED.store(allocated(), Ordering::Relaxed);
+}
+
+/// Calls `f` and returns an error if ecCodes had more than `limit` bytes allocated
+/// on the current thread at once in the meantime. Memory released during the call is returned to the budget.
+///
+/// Allocations are never refused, as ecCodes cannot recover from a failed allocation,
+/// so the limit does not protect from running out of memory. Memory used within a nested call
+/// counts towards the limit of the outer call.
+///
+/// Limits do not apply when memory procedures have not been [installed](install).
+pub fn with_limit<T>(limit: usize, f: impl FnOnce() -> T) -> Result<T, MemoryLimitExceeded> {
+    let previous = BUDGET.replace(Some(Budget {
+        limit,
+        used: 0,
+        peak: 0,
+    }));
+
+    // budget of the call has to be restored also when `f` panics
+    struct Restore(Option<Budget>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let budget = BUDGET.replace(self.0);
+
+            if let (Some(outer), Some(budget)) = (self.0, budget) {
+                BUDGET.set(Some(Budget {
+                    used: outer.used.saturating_add(budget.used),
+                    peak: outer.peak.max(outer.used.saturating_add(budget.peak)),
+                    ..outer
+                }));
+            }
+        }
+    }
+
+    let restore = Restore(previous);
+    let result = f();
+    let budget = BUDGET.get();
+    drop(restore);
+
+    match budget {
+        Some(budget) if budget.peak > limit => Err(MemoryLimitExceeded {
+            limit,
+            peak: budget.peak,
+        }),
+        _ => Ok(result),
+    }
+}
+
+/// Charges `size` bytes to the budget of the current thread.
+fn charge(size: usize) {
+    let _ = BUDGET.try_with(|budget| {
+        if let Some(mut b) = budget.get() {
+            b.used = b.used.saturating_add(size);
+            b.peak = b.peak.max(b.used);
+            budget.set(Some(b));
+        }
+    });
+}
+
+/// Returns `size` bytes to the budget of the current thread.
+fn release(size: usize) {
+    let _ = BUDGET.try_with(|budget| {
+        if let Some(mut b) = budget.get() {
+            b.used = b.used.saturating_sub(size);
+            budget.set(Some(b));
+        }
+    });
+}
+
+fn track_allocation(size: usize) {
+    let allocated = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_ALLOCATED.fetch_max(allocated, Ordering::Relaxed);
+}
+
+fn track_deallocation(size: usize) {
+    ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+    release(size);
+}
+
+fn layout(size: usize) -> Option<Layout> {
+    Layout::from_size_align(size.checked_add(HEADER)?, HEADER).ok()
+}
+
+/// Returns the start of the block and its size stored in the header.
+unsafe fn block(data: *mut c_void) -> (*mut u8, usize) {
+    unsafe {
+        let block = data.cast::<u8>().sub(HEADER);
+        (block, block.cast::<usize>().read())
+    }
+}
+
+unsafe extern "C" fn malloc(_context: *const codes_context, size: usize) -> *mut c_void {
+    let Some(layout) = layout(size) else {
+        return ptr::null_mut();
+    };
+
+    let block = unsafe { alloc::alloc(layout) };
+    if block.is_null() {
+        return ptr::null_mut();
+    }
+
+    charge(size);
+    track_allocation(size);
+
+    unsafe {
+        block.cast::<usize>().write(size);
+        block.add(HEADER).cast()
+    }
+}
+
+unsafe extern "C" fn free(_context: *const codes_context, data: *mut c_void) {
+    if data.is_null() {
+        return;
+    }
+
+    unsafe {
+        let (block, size) = block(data);
+        alloc::dealloc(block, layout(size).unwrap());
+        track_deallocation(size);
+    }
+}
+
+unsafe extern "C" fn realloc(
+    context: *const codes_context,
+    data: *mut c_void,
+    size: usize,
+) -> *mut c_void {
+    if data.is_null() {
+        return unsafe { malloc(context, size) };
+    }
+
+    let (block, old_size) = unsafe { block(data) };
+
+    let Some(new_layout) = layout(size) else {
+        return ptr::null_mut();
+    };
+
+    let new_block = unsafe { alloc::realloc(block, layout(old_size).unwrap(), new_layout.size()) };
+    if new_block.is_null() {
+        return ptr::null_mut();
+    }
+
+    if size > old_size {
+        charge(size - old_size);
+        track_allocation(size - old_size);
+    } else {
+        track_deallocation(old_size - size);
+    }
+
+    unsafe {
+        new_block.cast::<usize>().write(size);
+        new_block.add(HEADER).cast()
+    }
+}
@@ -0,0 +1,2677 @@
+/* automatically generated by rust-bindgen 0.71.1 */
+
+// Bindings for ecCodes 2.34, generated on x86_64 Ubuntu 24.04 with libeccodes 2.34.1.
+// Generation is restricted to ecCodes API, so the bindings do not depend on the host libc.
+
+pub use libc::{FILE, off_t};
+
+pub const ECCODES_VERSION_STR: &[u8; 7] = b"2.34.1\0";
+pub const ECCODES_MAJOR_VERSION: u32 = 2;
+pub const ECCODES_MINOR_VERSION: u32 = 34;
+pub const ECCODES_REVISION_VERSION: u32 = 1;
+pub const ECCODES_VERSION: u32 = 23401;
+pub const GRIB_SECTION_PRODUCT: u32 = 1;
+pub const GRIB_SECTION_GRID: u32 = 2;
+pub const GRIB_SECTION_LOCAL: u32 = 4;
+pub const GRIB_SECTION_DATA: u32 = 8;
+pub const GRIB_SECTION_BITMAP: u32 = 16;
+pub const GRIB_LOG_INFO: u32 = 0;
+pub const GRIB_LOG_WARNING: u32 = 1;
+pub const GRIB_LOG_ERROR: u32 = 2;
+pub const GRIB_LOG_FATAL: u32 = 3;
+pub const GRIB_LOG_DEBUG: u32 = 4;
+pub const GRIB_TYPE_UNDEFINED: u32 = 0;
+pub const GRIB_TYPE_LONG: u32 = 1;
+pub const GRIB_TYPE_DOUBLE: u32 = 2;
+pub const GRIB_TYPE_STRING: u32 = 3;
+pub const GRIB_TYPE_BYTES: u32 = 4;
+pub const GRIB_TYPE_SECTION: u32 = 5;
+pub const GRIB_TYPE_LABEL: u32 = 6;
+pub const GRIB_TYPE_MISSING: u32 = 7;
+pub const GRIB_MISSING_LONG: u32 = 2147483647;
+pub const GRIB_MISSING_DOUBLE : f64 = - 10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0 ;
+pub const GRIB_DUMP_FLAG_READ_ONLY: u32 = 1;
+pub const GRIB_DUMP_FLAG_DUMP_OK: u32 = 2;
+pub const GRIB_DUMP_FLAG_VALUES: u32 = 4;
+pub const GRIB_DUMP_FLAG_CODED: u32 = 8;
+pub const GRIB_DUMP_FLAG_OCTET: u32 = 16;
+pub const GRIB_DUMP_FLAG_ALIASES: u32 = 32;
+pub const GRIB_DUMP_FLAG_TYPE: u32 = 64;
+pub const GRIB_DUMP_FLAG_HEXADECIMAL: u32 = 128;
+pub const GRIB_DUMP_FLAG_NO_DATA: u32 = 256;
+pub const GRIB_DUMP_FLAG_ALL_DATA: u32 = 512;
+pub const GRIB_DUMP_FLAG_ALL_ATTRIBUTES: u32 = 1024;
+pub const GRIB_NEAREST_SAME_GRID: u32 = 1;
+pub const GRIB_NEAREST_SAME_DATA: u32 = 2;
+pub const GRIB_NEAREST_SAME_POINT: u32 = 4;
+pub const GRIB_GEOITERATOR_NO_VALUES: u32 = 1;
+pub const GRIB_KEYS_ITERATOR_ALL_KEYS: u32 = 0;
+pub const GRIB_KEYS_ITERATOR_SKIP_READ_ONLY: u32 = 1;
+pub const GRIB_KEYS_ITERATOR_SKIP_OPTIONAL: u32 = 2;
+pub const GRIB_KEYS_ITERATOR_SKIP_EDITION_SPECIFIC: u32 = 4;
+pub const GRIB_KEYS_ITERATOR_SKIP_CODED: u32 = 8;
+pub const GRIB_KEYS_ITERATOR_SKIP_COMPUTED: u32 = 16;
+pub const GRIB_KEYS_ITERATOR_SKIP_DUPLICATES: u32 = 32;
+pub const GRIB_KEYS_ITERATOR_SKIP_FUNCTION: u32 = 64;
+pub const GRIB_KEYS_ITERATOR_DUMP_ONLY: u32 = 128;
+pub const GRIB_UTIL_GRID_SPEC_REGULAR_LL: u32 = 1;
+pub const GRIB_UTIL_GRID_SPEC_ROTATED_LL: u32 = 2;
+pub const GRIB_UTIL_GRID_SPEC_REGULAR_GG: u32 = 3;
+pub const GRIB_UTIL_GRID_SPEC_ROTATED_GG: u32 = 4;
+pub const GRIB_UTIL_GRID_SPEC_REDUCED_GG: u32 = 5;
+pub const GRIB_UTIL_GRID_SPEC_SH: u32 = 6;
+pub const GRIB_UTIL_GRID_SPEC_REDUCED_LL: u32 = 7;
+pub const GRIB_UTIL_GRID_SPEC_POLAR_STEREOGRAPHIC: u32 = 8;
+pub const GRIB_UTIL_GRID_SPEC_REDUCED_ROTATED_GG: u32 = 9;
+pub const GRIB_UTIL_GRID_SPEC_LAMBERT_AZIMUTHAL_EQUAL_AREA: u32 = 10;
+pub const GRIB_UTIL_GRID_SPEC_LAMBERT_CONFORMAL: u32 = 11;
+pub const GRIB_UTIL_GRID_SPEC_UNSTRUCTURED: u32 = 12;
+pub const GRIB_UTIL_GRID_SPEC_HEALPIX: u32 = 13;
+pub const GRIB_UTIL_PACKING_TYPE_SAME_AS_INPUT: u32 = 0;
+pub const GRIB_UTIL_PACKING_TYPE_SPECTRAL_COMPLEX: u32 = 1;
+pub const GRIB_UTIL_PACKING_TYPE_SPECTRAL_SIMPLE: u32 = 2;
+pub const GRIB_UTIL_PACKING_TYPE_JPEG: u32 = 3;
+pub const GRIB_UTIL_PACKING_TYPE_GRID_COMPLEX: u32 = 4;
+pub const GRIB_UTIL_PACKING_TYPE_GRID_SIMPLE: u32 = 5;
+pub const GRIB_UTIL_PACKING_TYPE_GRID_SIMPLE_MATRIX: u32 = 6;
+pub const GRIB_UTIL_PACKING_TYPE_GRID_SECOND_ORDER: u32 = 7;
+pub const GRIB_UTIL_PACKING_TYPE_CCSDS: u32 = 8;
+pub const GRIB_UTIL_PACKING_TYPE_IEEE: u32 = 9;
+pub const GRIB_UTIL_PACKING_SAME_AS_INPUT: u32 = 0;
+pub const GRIB_UTIL_PACKING_USE_PROVIDED: u32 = 1;
+pub const GRIB_UTIL_ACCURACY_SAME_BITS_PER_VALUES_AS_INPUT: u32 = 0;
+pub const GRIB_UTIL_ACCURACY_USE_PROVIDED_BITS_PER_VALUES: u32 = 1;
+pub const GRIB_UTIL_ACCURACY_SAME_DECIMAL_SCALE_FACTOR_AS_INPUT: u32 = 2;
+pub const GRIB_UTIL_ACCURACY_USE_PROVIDED_DECIMAL_SCALE_FACTOR: u32 = 3;
+pub const GRIB_SUCCESS: u32 = 0;
+pub const GRIB_END_OF_FILE: i32 = -1;
+pub const GRIB_INTERNAL_ERROR: i32 = -2;
+pub const GRIB_BUFFER_TOO_SMALL: i32 = -3;
+pub const GRIB_NOT_IMPLEMENTED: i32 = -4;
+pub const GRIB_7777_NOT_FOUND: i32 = -5;
+pub const GRIB_ARRAY_TOO_SMALL: i32 = -6;
+pub const GRIB_FILE_NOT_FOUND: i32 = -7;
+pub const GRIB_CODE_NOT_FOUND_IN_TABLE: i32 = -8;
+pub const GRIB_WRONG_ARRAY_SIZE: i32 = -9;
+pub const GRIB_NOT_FOUND: i32 = -10;
+pub const GRIB_IO_PROBLEM: i32 = -11;
+pub const GRIB_INVALID_MESSAGE: i32 = -12;
+pub const GRIB_DECODING_ERROR: i32 = -13;
+pub const GRIB_ENCODING_ERROR: i32 = -14;
+pub const GRIB_NO_MORE_IN_SET: i32 = -15;
+pub const GRIB_GEOCALCULUS_PROBLEM: i32 = -16;
+pub const GRIB_OUT_OF_MEMORY: i32 = -17;
+pub const GRIB_READ_ONLY: i32 = -18;
+pub const GRIB_INVALID_ARGUMENT: i32 = -19;
+pub const GRIB_NULL_HANDLE: i32 = -20;
+pub const GRIB_INVALID_SECTION_NUMBER: i32 = -21;
+pub const GRIB_VALUE_CANNOT_BE_MISSING: i32 = -22;
+pub const GRIB_WRONG_LENGTH: i32 = -23;
+pub const GRIB_INVALID_TYPE: i32 = -24;
+pub const GRIB_WRONG_STEP: i32 = -25;
+pub const GRIB_WRONG_STEP_UNIT: i32 = -26;
+pub const GRIB_INVALID_FILE: i32 = -27;
+pub const GRIB_INVALID_GRIB: i32 = -28;
+pub const GRIB_INVALID_INDEX: i32 = -29;
+pub const GRIB_INVALID_ITERATOR: i32 = -30;
+pub const GRIB_INVALID_KEYS_ITERATOR: i32 = -31;
+pub const GRIB_INVALID_NEAREST: i32 = -32;
+pub const GRIB_INVALID_ORDERBY: i32 = -33;
+pub const GRIB_MISSING_KEY: i32 = -34;
+pub const GRIB_OUT_OF_AREA: i32 = -35;
+pub const GRIB_CONCEPT_NO_MATCH: i32 = -36;
+pub const GRIB_HASH_ARRAY_NO_MATCH: i32 = -37;
+pub const GRIB_NO_DEFINITIONS: i32 = -38;
+pub const GRIB_WRONG_TYPE: i32 = -39;
+pub const GRIB_END: i32 = -40;
+pub const GRIB_NO_VALUES: i32 = -41;
+pub const GRIB_WRONG_GRID: i32 = -42;
+pub const GRIB_END_OF_INDEX: i32 = -43;
+pub const GRIB_NULL_INDEX: i32 = -44;
+pub const GRIB_PREMATURE_END_OF_FILE: i32 = -45;
+pub const GRIB_INTERNAL_ARRAY_TOO_SMALL: i32 = -46;
+pub const GRIB_MESSAGE_TOO_LARGE: i32 = -47;
+pub const GRIB_CONSTANT_FIELD: i32 = -48;
+pub const GRIB_SWITCH_NO_MATCH: i32 = -49;
+pub const GRIB_UNDERFLOW: i32 = -50;
+pub const GRIB_MESSAGE_MALFORMED: i32 = -51;
+pub const GRIB_CORRUPTED_INDEX: i32 = -52;
+pub const GRIB_INVALID_BPV: i32 = -53;
+pub const GRIB_DIFFERENT_EDITION: i32 = -54;
+pub const GRIB_VALUE_DIFFERENT: i32 = -55;
+pub const GRIB_INVALID_KEY_VALUE: i32 = -56;
+pub const GRIB_STRING_TOO_SMALL: i32 = -57;
+pub const GRIB_WRONG_CONVERSION: i32 = -58;
+pub const GRIB_MISSING_BUFR_ENTRY: i32 = -59;
+pub const GRIB_NULL_POINTER: i32 = -60;
+pub const GRIB_ATTRIBUTE_CLASH: i32 = -61;
+pub const GRIB_TOO_MANY_ATTRIBUTES: i32 = -62;
+pub const GRIB_ATTRIBUTE_NOT_FOUND: i32 = -63;
+pub const GRIB_UNSUPPORTED_EDITION: i32 = -64;
+pub const GRIB_OUT_OF_RANGE: i32 = -65;
+pub const GRIB_WRONG_BITMAP_SIZE: i32 = -66;
+pub const GRIB_FUNCTIONALITY_NOT_ENABLED: i32 = -67;
+pub const GRIB_VALUE_MISMATCH: i32 = -68;
+pub const GRIB_DOUBLE_VALUE_MISMATCH: i32 = -69;
+pub const GRIB_LONG_VALUE_MISMATCH: i32 = -70;
+pub const GRIB_BYTE_VALUE_MISMATCH: i32 = -71;
+pub const GRIB_STRING_VALUE_MISMATCH: i32 = -72;
+pub const GRIB_OFFSET_MISMATCH: i32 = -73;
+pub const GRIB_COUNT_MISMATCH: i32 = -74;
+pub const GRIB_NAME_MISMATCH: i32 = -75;
+pub const GRIB_TYPE_MISMATCH: i32 = -76;
+pub const GRIB_TYPE_AND_VALUE_MISMATCH: i32 = -77;
+pub const GRIB_UNABLE_TO_COMPARE_ACCESSORS: i32 = -78;
+pub const GRIB_ASSERTION_FAILURE: i32 = -79;
+pub const CODES_VERSION: u32 = 23401;
+pub const CODES_SECTION_PRODUCT: u32 = 1;
+pub const CODES_SECTION_GRID: u32 = 2;
+pub const CODES_SECTION_LOCAL: u32 = 4;
+pub const CODES_SECTION_DATA: u32 = 8;
+pub const CODES_SECTION_BITMAP: u32 = 16;
+pub const CODES_LOG_INFO: u32 = 0;
+pub const CODES_LOG_WARNING: u32 = 1;
+pub const CODES_LOG_ERROR: u32 = 2;
+pub const CODES_LOG_FATAL: u32 = 3;
+pub const CODES_LOG_DEBUG: u32 = 4;
+pub const CODES_TYPE_UNDEFINED: u32 = 0;
+pub const CODES_TYPE_LONG: u32 = 1;
+pub const CODES_TYPE_DOUBLE: u32 = 2;
+pub const CODES_TYPE_STRING: u32 = 3;
+pub const CODES_TYPE_BYTES: u32 = 4;
+pub const CODES_TYPE_SECTION: u32 = 5;
+pub const CODES_TYPE_LABEL: u32 = 6;
+pub const CODES_TYPE_MISSING: u32 = 7;
+pub const CODES_MISSING_LONG: u32 = 2147483647;
+pub const CODES_MISSING_DOUBLE : f64 = - 10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0 ;
+pub const CODES_DUMP_FLAG_READ_ONLY: u32 = 1;
+pub const CODES_DUMP_FLAG_DUMP_OK: u32 = 2;
+pub const CODES_DUMP_FLAG_VALUES: u32 = 4;
+pub const CODES_DUMP_FLAG_CODED: u32 = 8;
+pub const CODES_DUMP_FLAG_OCTET: u32 = 16;
+pub const CODES_DUMP_FLAG_ALIASES: u32 = 32;
+pub const CODES_DUMP_FLAG_TYPE: u32 = 64;
+pub const CODES_DUMP_FLAG_HEXADECIMAL: u32 = 128;
+pub const CODES_DUMP_FLAG_NO_DATA: u32 = 256;
+pub const CODES_DUMP_FLAG_ALL_DATA: u32 = 512;
+pub const CODES_DUMP_FLAG_ALL_ATTRIBUTES: u32 = 1024;
+pub const CODES_NEAREST_SAME_GRID: u32 = 1;
+pub const CODES_NEAREST_SAME_DATA: u32 = 2;
+pub const CODES_NEAREST_SAME_POINT: u32 = 4;
+pub const CODES_GEOITERATOR_NO_VALUES: u32 = 1;
+pub const CODES_KEYS_ITERATOR_ALL_KEYS: u32 = 0;
+pub const CODES_KEYS_ITERATOR_SKIP_READ_ONLY: u32 = 1;
+pub const CODES_KEYS_ITERATOR_SKIP_OPTIONAL: u32 = 2;
+pub const CODES_KEYS_ITERATOR_SKIP_EDITION_SPECIFIC: u32 = 4;
+pub const CODES_KEYS_ITERATOR_SKIP_CODED: u32 = 8;
+pub const CODES_KEYS_ITERATOR_SKIP_COMPUTED: u32 = 16;
+pub const CODES_KEYS_ITERATOR_SKIP_DUPLICATES: u32 = 32;
+pub const CODES_KEYS_ITERATOR_SKIP_FUNCTION: u32 = 64;
+pub const CODES_KEYS_ITERATOR_DUMP_ONLY: u32 = 128;
+pub const CODES_UTIL_GRID_SPEC_REGULAR_LL: u32 = 1;
+pub const CODES_UTIL_GRID_SPEC_ROTATED_LL: u32 = 2;
+pub const CODES_UTIL_GRID_SPEC_REGULAR_GG: u32 = 3;
+pub const CODES_UTIL_GRID_SPEC_ROTATED_GG: u32 = 4;
+pub const CODES_UTIL_GRID_SPEC_REDUCED_GG: u32 = 5;
+pub const CODES_UTIL_GRID_SPEC_SH: u32 = 6;
+pub const CODES_UTIL_GRID_SPEC_REDUCED_LL: u32 = 7;
+pub const CODES_UTIL_GRID_SPEC_POLAR_STEREOGRAPHIC: u32 = 8;
+pub const CODES_UTIL_GRID_SPEC_REDUCED_ROTATED_GG: u32 = 9;
+pub const CODES_UTIL_GRID_SPEC_LAMBERT_AZIMUTHAL_EQUAL_AREA: u32 = 10;
+pub const CODES_UTIL_GRID_SPEC_LAMBERT_CONFORMAL: u32 = 11;
+pub const CODES_UTIL_GRID_SPEC_UNSTRUCTURED: u32 = 12;
+pub const CODES_UTIL_GRID_SPEC_HEALPIX: u32 = 13;
+pub const CODES_UTIL_PACKING_TYPE_SAME_AS_INPUT: u32 = 0;
+pub const CODES_UTIL_PACKING_TYPE_SPECTRAL_COMPLEX: u32 = 1;
+pub const CODES_UTIL_PACKING_TYPE_SPECTRAL_SIMPLE: u32 = 2;
+pub const CODES_UTIL_PACKING_TYPE_JPEG: u32 = 3;
+pub const CODES_UTIL_PACKING_TYPE_GRID_COMPLEX: u32 = 4;
+pub const CODES_UTIL_PACKING_TYPE_GRID_SIMPLE: u32 = 5;
+pub const CODES_UTIL_PACKING_TYPE_GRID_SIMPLE_MATRIX: u32 = 6;
+pub const CODES_UTIL_PACKING_TYPE_GRID_SECOND_ORDER: u32 = 7;
+pub const CODES_UTIL_PACKING_TYPE_CCSDS: u32 = 8;
+pub const CODES_UTIL_PACKING_TYPE_IEEE: u32 = 9;
+pub const CODES_UTIL_PACKING_SAME_AS_INPUT: u32 = 0;
+pub const CODES_UTIL_PACKING_USE_PROVIDED: u32 = 1;
+pub const CODES_UTIL_ACCURACY_SAME_BITS_PER_VALUES_AS_INPUT: u32 = 0;
+pub const CODES_UTIL_ACCURACY_USE_PROVIDED_BITS_PER_VALUES: u32 = 1;
+pub const CODES_UTIL_ACCURACY_SAME_DECIMAL_SCALE_FACTOR_AS_INPUT: u32 = 2;
+pub const CODES_UTIL_ACCURACY_USE_PROVIDED_DECIMAL_SCALE_FACTOR: u32 = 3;
+pub const CODES_SUCCESS: u32 = 0;
+pub const CODES_END_OF_FILE: i32 = -1;
+pub const CODES_INTERNAL_ERROR: i32 = -2;
+pub const CODES_BUFFER_TOO_SMALL: i32 = -3;
+pub const CODES_NOT_IMPLEMENTED: i32 = -4;
+pub const CODES_7777_NOT_FOUND: i32 = -5;
+pub const CODES_ARRAY_TOO_SMALL: i32 = -6;
+pub const CODES_FILE_NOT_FOUND: i32 = -7;
+pub const CODES_CODE_NOT_FOUND_IN_TABLE: i32 = -8;
+pub const CODES_WRONG_ARRAY_SIZE: i32 = -9;
+pub const CODES_NOT_FOUND: i32 = -10;
+pub const CODES_IO_PROBLEM: i32 = -11;
+pub const CODES_INVALID_MESSAGE: i32 = -12;
+pub const CODES_DECODING_ERROR: i32 = -13;
+pub const CODES_ENCODING_ERROR: i32 = -14;
+pub const CODES_NO_MORE_IN_SET: i32 = -15;
+pub const CODES_GEOCALCULUS_PROBLEM: i32 = -16;
+pub const CODES_OUT_OF_MEMORY: i32 = -17;
+pub const CODES_READ_ONLY: i32 = -18;
+pub const CODES_INVALID_ARGUMENT: i32 = -19;
+pub const CODES_NULL_HANDLE: i32 = -20;
+pub const CODES_INVALID_SECTION_NUMBER: i32 = -21;
+pub const CODES_VALUE_CANNOT_BE_MISSING: i32 = -22;
+pub const CODES_WRONG_LENGTH: i32 = -23;
+pub const CODES_INVALID_TYPE: i32 = -24;
+pub const CODES_WRONG_STEP: i32 = -25;
+pub const CODES_WRONG_STEP_UNIT: i32 = -26;
+pub const CODES_INVALID_FILE: i32 = -27;
+pub const CODES_INVALID_GRIB: i32 = -28;
+pub const CODES_INVALID_INDEX: i32 = -29;
+pub const CODES_INVALID_ITERATOR: i32 = -30;
+pub const CODES_INVALID_KEYS_ITERATOR: i32 = -31;
+pub const CODES_INVALID_NEAREST: i32 = -32;
+pub const CODES_INVALID_ORDERBY: i32 = -33;
+pub const CODES_MISSING_KEY: i32 = -34;
+pub const CODES_OUT_OF_AREA: i32 = -35;
+pub const CODES_CONCEPT_NO_MATCH: i32 = -36;
+pub const CODES_HASH_ARRAY_NO_MATCH: i32 = -37;
+pub const CODES_NO_DEFINITIONS: i32 = -38;
+pub const CODES_WRONG_TYPE: i32 = -39;
+pub const CODES_END: i32 = -40;
+pub const CODES_NO_VALUES: i32 = -41;
+pub const CODES_WRONG_GRID: i32 = -42;
+pub const CODES_END_OF_INDEX: i32 = -43;
+pub const CODES_NULL_INDEX: i32 = -44;
+pub const CODES_PREMATURE_END_OF_FILE: i32 = -45;
+pub const CODES_INTERNAL_ARRAY_TOO_SMALL: i32 = -46;
+pub const CODES_MESSAGE_TOO_LARGE: i32 = -47;
+pub const CODES_CONSTANT_FIELD: i32 = -48;
+pub const CODES_SWITCH_NO_MATCH: i32 = -49;
+pub const CODES_UNDERFLOW: i32 = -50;
+pub const CODES_MESSAGE_MALFORMED: i32 = -51;
+pub const CODES_CORRUPTED_INDEX: i32 = -52;
+pub const CODES_INVALID_BPV: i32 = -53;
+pub const CODES_DIFFERENT_EDITION: i32 = -54;
+pub const CODES_VALUE_DIFFERENT: i32 = -55;
+pub const CODES_INVALID_KEY_VALUE: i32 = -56;
+pub const CODES_STRING_TOO_SMALL: i32 = -57;
+pub const CODES_WRONG_CONVERSION: i32 = -58;
+pub const CODES_MISSING_BUFR_ENTRY: i32 = -59;
+pub const CODES_NULL_POINTER: i32 = -60;
+pub const CODES_ATTRIBUTE_CLASH: i32 = -61;
+pub const CODES_TOO_MANY_ATTRIBUTES: i32 = -62;
+pub const CODES_ATTRIBUTE_NOT_FOUND: i32 = -63;
+pub const CODES_UNSUPPORTED_EDITION: i32 = -64;
+pub const CODES_OUT_OF_RANGE: i32 = -65;
+pub const CODES_WRONG_BITMAP_SIZE: i32 = -66;
+pub const CODES_FUNCTIONALITY_NOT_ENABLED: i32 = -67;
+pub const CODES_VALUE_MISMATCH: i32 = -68;
+pub const CODES_DOUBLE_VALUE_MISMATCH: i32 = -69;
+pub const CODES_LONG_VALUE_MISMATCH: i32 = -70;
+pub const CODES_BYTE_VALUE_MISMATCH: i32 = -71;
+pub const CODES_STRING_VALUE_MISMATCH: i32 = -72;
+pub const CODES_OFFSET_MISMATCH: i32 = -73;
+pub const CODES_COUNT_MISMATCH: i32 = -74;
+pub const CODES_NAME_MISMATCH: i32 = -75;
+pub const CODES_TYPE_MISMATCH: i32 = -76;
+pub const CODES_TYPE_AND_VALUE_MISMATCH: i32 = -77;
+pub const CODES_UNABLE_TO_COMPARE_ACCESSORS: i32 = -78;
+pub const CODES_ASSERTION_FAILURE: i32 = -79;
+pub const ProductKind_PRODUCT_ANY: ProductKind = 0;
+pub const ProductKind_PRODUCT_GRIB: ProductKind = 1;
+pub const ProductKind_PRODUCT_BUFR: ProductKind = 2;
+pub const ProductKind_PRODUCT_METAR: ProductKind = 3;
+pub const ProductKind_PRODUCT_GTS: ProductKind = 4;
+pub const ProductKind_PRODUCT_TAF: ProductKind = 5;
+pub type ProductKind = ::std::os::raw::c_uint;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_key_value_list {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_values {
+    pub name: *const ::std::os::raw::c_char,
+    pub type_: ::std::os::raw::c_int,
+    pub long_value: ::std::os::raw::c_long,
+    pub double_value: f64,
+    pub string_value: *const ::std::os::raw::c_char,
+    pub error: ::std::os::raw::c_int,
+    pub has_value: ::std::os::raw::c_int,
+    pub equal: ::std::os::raw::c_int,
+    pub next: *mut grib_values,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_handle {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_multi_handle {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_context {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_iterator {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_nearest {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_keys_iterator {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bufr_keys_iterator {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_fieldset {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_order_by {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_where {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_sarray {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_oarray {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_darray {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_iarray {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_vdarray {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_vsarray {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_viarray {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bufr_descriptor {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bufr_descriptors_array {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bufr_descriptors_map_list {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn grib_fieldset_new_from_files(
+        c: *mut grib_context,
+        filenames: *mut *const ::std::os::raw::c_char,
+        nfiles: ::std::os::raw::c_int,
+        keys: *mut *const ::std::os::raw::c_char,
+        nkeys: ::std::os::raw::c_int,
+        where_string: *const ::std::os::raw::c_char,
+        order_by_string: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_fieldset;
+}
+unsafe extern "C" {
+    pub fn grib_fieldset_delete(set: *mut grib_fieldset);
+}
+unsafe extern "C" {
+    pub fn grib_fieldset_rewind(set: *mut grib_fieldset);
+}
+unsafe extern "C" {
+    pub fn grib_fieldset_apply_order_by(
+        set: *mut grib_fieldset,
+        order_by_string: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_fieldset_next_handle(
+        set: *mut grib_fieldset,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_fieldset_count(set: *const grib_fieldset) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_values_check(
+        h: *mut grib_handle,
+        values: *mut grib_values,
+        count: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_index {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn grib_index_new_from_file(
+        c: *mut grib_context,
+        filename: *const ::std::os::raw::c_char,
+        keys: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_index;
+}
+unsafe extern "C" {
+    pub fn grib_index_new(
+        c: *mut grib_context,
+        keys: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_index;
+}
+unsafe extern "C" {
+    pub fn codes_index_set_product_kind(
+        index: *mut grib_index,
+        product_kind: ProductKind,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_index_set_unpack_bufr(
+        index: *mut grib_index,
+        unpack: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_index_add_file(
+        index: *mut grib_index,
+        filename: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_index_write(
+        index: *mut grib_index,
+        filename: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_index_read(
+        c: *mut grib_context,
+        filename: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_index;
+}
+unsafe extern "C" {
+    pub fn grib_index_get_size(
+        index: *const grib_index,
+        key: *const ::std::os::raw::c_char,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_index_get_long(
+        index: *const grib_index,
+        key: *const ::std::os::raw::c_char,
+        values: *mut ::std::os::raw::c_long,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_index_get_double(
+        index: *const grib_index,
+        key: *const ::std::os::raw::c_char,
+        values: *mut f64,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_index_get_string(
+        index: *const grib_index,
+        key: *const ::std::os::raw::c_char,
+        values: *mut *mut ::std::os::raw::c_char,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_index_select_long(
+        index: *mut grib_index,
+        key: *const ::std::os::raw::c_char,
+        value: ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_index_select_double(
+        index: *mut grib_index,
+        key: *const ::std::os::raw::c_char,
+        value: f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_index_select_string(
+        index: *mut grib_index,
+        key: *const ::std::os::raw::c_char,
+        value: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_handle_new_from_index(
+        index: *mut grib_index,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_index_delete(index: *mut grib_index);
+}
+unsafe extern "C" {
+    pub fn grib_count_in_file(
+        c: *mut grib_context,
+        f: *mut FILE,
+        n: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_count_in_filename(
+        c: *mut grib_context,
+        filename: *const ::std::os::raw::c_char,
+        n: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_handle_new_from_file(
+        c: *mut grib_context,
+        f: *mut FILE,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_write_message(
+        h: *const grib_handle,
+        file: *const ::std::os::raw::c_char,
+        mode: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_string_list {
+    pub value: *mut ::std::os::raw::c_char,
+    pub count: ::std::os::raw::c_int,
+    pub next: *mut grib_string_list,
+}
+unsafe extern "C" {
+    pub fn grib_util_sections_copy(
+        hfrom: *mut grib_handle,
+        hto: *mut grib_handle,
+        what: ::std::os::raw::c_int,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_util_get_param_id(
+        mars_param: *const ::std::os::raw::c_char,
+    ) -> *mut grib_string_list;
+}
+unsafe extern "C" {
+    pub fn grib_util_get_mars_param(
+        param_id: *const ::std::os::raw::c_char,
+    ) -> *mut grib_string_list;
+}
+unsafe extern "C" {
+    pub fn grib_handle_new_from_message(
+        c: *mut grib_context,
+        data: *const ::std::os::raw::c_void,
+        data_len: usize,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_handle_new_from_multi_message(
+        c: *mut grib_context,
+        data: *mut *mut ::std::os::raw::c_void,
+        data_len: *mut usize,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_handle_new_from_message_copy(
+        c: *mut grib_context,
+        data: *const ::std::os::raw::c_void,
+        data_len: usize,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_handle_new_from_samples(
+        c: *mut grib_context,
+        sample_name: *const ::std::os::raw::c_char,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_handle_clone(h: *const grib_handle) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_handle_clone_headers_only(h: *const grib_handle) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_handle_delete(h: *mut grib_handle) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_multi_handle_new(c: *mut grib_context) -> *mut grib_multi_handle;
+}
+unsafe extern "C" {
+    pub fn grib_multi_handle_append(
+        h: *mut grib_handle,
+        start_section: ::std::os::raw::c_int,
+        mh: *mut grib_multi_handle,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_multi_handle_delete(mh: *mut grib_multi_handle) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_multi_handle_write(
+        mh: *mut grib_multi_handle,
+        f: *mut FILE,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_message(
+        h: *const grib_handle,
+        message: *mut *const ::std::os::raw::c_void,
+        message_length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_message_copy(
+        h: *const grib_handle,
+        message: *mut ::std::os::raw::c_void,
+        message_length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_iterator_new(
+        h: *const grib_handle,
+        flags: ::std::os::raw::c_ulong,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_iterator;
+}
+unsafe extern "C" {
+    pub fn grib_get_data(
+        h: *const grib_handle,
+        lats: *mut f64,
+        lons: *mut f64,
+        values: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_iterator_next(
+        i: *mut grib_iterator,
+        lat: *mut f64,
+        lon: *mut f64,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_iterator_previous(
+        i: *mut grib_iterator,
+        lat: *mut f64,
+        lon: *mut f64,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_iterator_has_next(i: *mut grib_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_iterator_reset(i: *mut grib_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_iterator_delete(i: *mut grib_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_nearest_new(
+        h: *const grib_handle,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_nearest;
+}
+unsafe extern "C" {
+    pub fn grib_nearest_find(
+        nearest: *mut grib_nearest,
+        h: *const grib_handle,
+        inlat: f64,
+        inlon: f64,
+        flags: ::std::os::raw::c_ulong,
+        outlats: *mut f64,
+        outlons: *mut f64,
+        values: *mut f64,
+        distances: *mut f64,
+        indexes: *mut ::std::os::raw::c_int,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_nearest_delete(nearest: *mut grib_nearest) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_nearest_find_multiple(
+        h: *const grib_handle,
+        is_lsm: ::std::os::raw::c_int,
+        inlats: *const f64,
+        inlons: *const f64,
+        npoints: ::std::os::raw::c_long,
+        outlats: *mut f64,
+        outlons: *mut f64,
+        values: *mut f64,
+        distances: *mut f64,
+        indexes: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_offset(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        offset: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_size(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_length(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_long(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        value: *mut ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_double(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_float(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        value: *mut f32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_double_element(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        i: ::std::os::raw::c_int,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_float_element(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        i: ::std::os::raw::c_int,
+        value: *mut f32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_double_elements(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        index_array: *const ::std::os::raw::c_int,
+        size: ::std::os::raw::c_long,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_float_elements(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        index_array: *const ::std::os::raw::c_int,
+        size: ::std::os::raw::c_long,
+        value: *mut f32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_string(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        mesg: *mut ::std::os::raw::c_char,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_string_array(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut *mut ::std::os::raw::c_char,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_bytes(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        bytes: *mut ::std::os::raw::c_uchar,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_double_array(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut f64,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_float_array(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut f32,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_long_array(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut ::std::os::raw::c_long,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_copy_namespace(
+        dest: *mut grib_handle,
+        name: *const ::std::os::raw::c_char,
+        src: *mut grib_handle,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_long(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        val: ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_double(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        val: f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_string(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        mesg: *const ::std::os::raw::c_char,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_bytes(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        bytes: *const ::std::os::raw::c_uchar,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_double_array(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const f64,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_float_array(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const f32,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_force_double_array(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const f64,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_force_float_array(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const f32,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_long_array(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const ::std::os::raw::c_long,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_string_array(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut *const ::std::os::raw::c_char,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_dump_content(
+        h: *const grib_handle,
+        out: *mut FILE,
+        mode: *const ::std::os::raw::c_char,
+        option_flags: ::std::os::raw::c_ulong,
+        arg: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn grib_dump_action_tree(c: *mut grib_context, f: *mut FILE);
+}
+pub type grib_free_proc = ::std::option::Option<
+    unsafe extern "C" fn(c: *const grib_context, data: *mut ::std::os::raw::c_void),
+>;
+pub type grib_malloc_proc = ::std::option::Option<
+    unsafe extern "C" fn(c: *const grib_context, length: usize) -> *mut ::std::os::raw::c_void,
+>;
+pub type grib_realloc_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const grib_context,
+        data: *mut ::std::os::raw::c_void,
+        length: usize,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+pub type grib_log_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const grib_context,
+        level: ::std::os::raw::c_int,
+        mesg: *const ::std::os::raw::c_char,
+    ),
+>;
+pub type grib_print_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const grib_context,
+        descriptor: *mut ::std::os::raw::c_void,
+        mesg: *const ::std::os::raw::c_char,
+    ),
+>;
+pub type grib_data_read_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const grib_context,
+        ptr: *mut ::std::os::raw::c_void,
+        size: usize,
+        stream: *mut ::std::os::raw::c_void,
+    ) -> usize,
+>;
+pub type grib_data_write_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const grib_context,
+        ptr: *const ::std::os::raw::c_void,
+        size: usize,
+        stream: *mut ::std::os::raw::c_void,
+    ) -> usize,
+>;
+pub type grib_data_tell_proc = ::std::option::Option<
+    unsafe extern "C" fn(c: *const grib_context, stream: *mut ::std::os::raw::c_void) -> off_t,
+>;
+pub type grib_data_seek_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const grib_context,
+        offset: off_t,
+        whence: ::std::os::raw::c_int,
+        stream: *mut ::std::os::raw::c_void,
+    ) -> off_t,
+>;
+pub type grib_data_eof_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const grib_context,
+        stream: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int,
+>;
+unsafe extern "C" {
+    pub fn grib_context_get_default() -> *mut grib_context;
+}
+unsafe extern "C" {
+    pub fn grib_context_delete(c: *mut grib_context);
+}
+unsafe extern "C" {
+    pub fn grib_gts_header_on(c: *mut grib_context);
+}
+unsafe extern "C" {
+    pub fn grib_gts_header_off(c: *mut grib_context);
+}
+unsafe extern "C" {
+    pub fn grib_gribex_mode_on(c: *mut grib_context);
+}
+unsafe extern "C" {
+    pub fn grib_get_gribex_mode(c: *mut grib_context) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_gribex_mode_off(c: *mut grib_context);
+}
+unsafe extern "C" {
+    pub fn grib_context_set_definitions_path(
+        c: *mut grib_context,
+        path: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn grib_context_set_samples_path(c: *mut grib_context, path: *const ::std::os::raw::c_char);
+}
+unsafe extern "C" {
+    pub fn grib_context_set_memory_proc(
+        c: *mut grib_context,
+        griballoc: grib_malloc_proc,
+        gribfree: grib_free_proc,
+        gribrealloc: grib_realloc_proc,
+    );
+}
+unsafe extern "C" {
+    pub fn grib_context_set_persistent_memory_proc(
+        c: *mut grib_context,
+        griballoc: grib_malloc_proc,
+        gribfree: grib_free_proc,
+    );
+}
+unsafe extern "C" {
+    pub fn grib_context_set_buffer_memory_proc(
+        c: *mut grib_context,
+        griballoc: grib_malloc_proc,
+        gribfree: grib_free_proc,
+        gribrealloc: grib_realloc_proc,
+    );
+}
+unsafe extern "C" {
+    pub fn grib_context_set_print_proc(c: *mut grib_context, printp: grib_print_proc);
+}
+unsafe extern "C" {
+    pub fn grib_context_set_logging_proc(c: *mut grib_context, logp: grib_log_proc);
+}
+unsafe extern "C" {
+    pub fn grib_multi_support_on(c: *mut grib_context);
+}
+unsafe extern "C" {
+    pub fn grib_multi_support_off(c: *mut grib_context);
+}
+unsafe extern "C" {
+    pub fn grib_multi_support_reset_file(c: *mut grib_context, f: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn grib_samples_path(c: *const grib_context) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn grib_definition_path(c: *const grib_context) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn grib_get_api_version() -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn grib_get_git_sha1() -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn grib_get_package_name() -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn grib_print_api_version(out: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_new(
+        h: *mut grib_handle,
+        filter_flags: ::std::os::raw::c_ulong,
+        name_space: *const ::std::os::raw::c_char,
+    ) -> *mut grib_keys_iterator;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_keys_iterator_new(
+        h: *mut grib_handle,
+        filter_flags: ::std::os::raw::c_ulong,
+    ) -> *mut bufr_keys_iterator;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_data_section_keys_iterator_new(
+        h: *mut grib_handle,
+    ) -> *mut bufr_keys_iterator;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_next(kiter: *mut grib_keys_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_keys_iterator_next(kiter: *mut bufr_keys_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_get_name(
+        kiter: *const grib_keys_iterator,
+    ) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_keys_iterator_get_name(
+        kiter: *const bufr_keys_iterator,
+    ) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_delete(kiter: *mut grib_keys_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_keys_iterator_delete(kiter: *mut bufr_keys_iterator)
+        -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_rewind(kiter: *mut grib_keys_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_keys_iterator_rewind(kiter: *mut bufr_keys_iterator)
+        -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_set_flags(
+        kiter: *mut grib_keys_iterator,
+        flags: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_get_long(
+        kiter: *const grib_keys_iterator,
+        v: *mut ::std::os::raw::c_long,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_get_double(
+        kiter: *const grib_keys_iterator,
+        v: *mut f64,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_get_float(
+        kiter: *const grib_keys_iterator,
+        v: *mut f32,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_get_string(
+        kiter: *const grib_keys_iterator,
+        v: *mut ::std::os::raw::c_char,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_keys_iterator_get_bytes(
+        kiter: *const grib_keys_iterator,
+        v: *mut ::std::os::raw::c_uchar,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_copy_key(
+        h1: *mut grib_handle,
+        h2: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+        type_: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_update_sections_lengths(h: *mut grib_handle);
+}
+unsafe extern "C" {
+    pub fn grib_get_error_message(code: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn grib_get_type_name(type_: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn grib_get_native_type(
+        h: *const grib_handle,
+        name: *const ::std::os::raw::c_char,
+        type_: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_check(
+        call: *const ::std::os::raw::c_char,
+        file: *const ::std::os::raw::c_char,
+        line: ::std::os::raw::c_int,
+        e: ::std::os::raw::c_int,
+        msg: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn grib_set_values(
+        h: *mut grib_handle,
+        grib_values: *mut grib_values,
+        arg_count: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_handle_new_from_partial_message_copy(
+        c: *mut grib_context,
+        data: *const ::std::os::raw::c_void,
+        size: usize,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_handle_new_from_partial_message(
+        c: *mut grib_context,
+        data: *const ::std::os::raw::c_void,
+        buflen: usize,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn grib_is_missing(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_is_defined(
+        h: *const grib_handle,
+        key: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_set_missing(
+        h: *mut grib_handle,
+        key: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_gaussian_latitudes(
+        truncation: ::std::os::raw::c_long,
+        latitudes: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_julian_to_datetime(
+        jd: f64,
+        year: *mut ::std::os::raw::c_long,
+        month: *mut ::std::os::raw::c_long,
+        day: *mut ::std::os::raw::c_long,
+        hour: *mut ::std::os::raw::c_long,
+        minute: *mut ::std::os::raw::c_long,
+        second: *mut ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_datetime_to_julian(
+        year: ::std::os::raw::c_long,
+        month: ::std::os::raw::c_long,
+        day: ::std::os::raw::c_long,
+        hour: ::std::os::raw::c_long,
+        minute: ::std::os::raw::c_long,
+        second: ::std::os::raw::c_long,
+        jd: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_julian_to_date(jdate: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn grib_date_to_julian(ddate: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn grib_get_reduced_row(
+        pl: ::std::os::raw::c_long,
+        lon_first: f64,
+        lon_last: f64,
+        npoints: *mut ::std::os::raw::c_long,
+        ilon_first: *mut ::std::os::raw::c_long,
+        ilon_last: *mut ::std::os::raw::c_long,
+    );
+}
+unsafe extern "C" {
+    pub fn grib_get_reduced_row_p(
+        pl: ::std::os::raw::c_long,
+        lon_first: f64,
+        lon_last: f64,
+        npoints: *mut ::std::os::raw::c_long,
+        olon_first: *mut f64,
+        olon_last: *mut f64,
+    );
+}
+unsafe extern "C" {
+    pub fn wmo_read_any_from_file(
+        f: *mut FILE,
+        buffer: *mut ::std::os::raw::c_void,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn wmo_read_grib_from_file(
+        f: *mut FILE,
+        buffer: *mut ::std::os::raw::c_void,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn wmo_read_bufr_from_file(
+        f: *mut FILE,
+        buffer: *mut ::std::os::raw::c_void,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn wmo_read_gts_from_file(
+        f: *mut FILE,
+        buffer: *mut ::std::os::raw::c_void,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn wmo_read_any_from_stream(
+        stream_data: *mut ::std::os::raw::c_void,
+        stream_proc: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::std::os::raw::c_void,
+                buffer: *mut ::std::os::raw::c_void,
+                len: ::std::os::raw::c_long,
+            ) -> ::std::os::raw::c_long,
+        >,
+        buffer: *mut ::std::os::raw::c_void,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn wmo_read_any_from_stream_malloc(
+        stream_data: *mut ::std::os::raw::c_void,
+        stream_proc: ::std::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::std::os::raw::c_void,
+                buffer: *mut ::std::os::raw::c_void,
+                len: ::std::os::raw::c_long,
+            ) -> ::std::os::raw::c_long,
+        >,
+        size: *mut usize,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn wmo_read_any_from_file_malloc(
+        f: *mut FILE,
+        headers_only: ::std::os::raw::c_int,
+        size: *mut usize,
+        offset: *mut off_t,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn wmo_read_gts_from_file_malloc(
+        f: *mut FILE,
+        headers_only: ::std::os::raw::c_int,
+        size: *mut usize,
+        offset: *mut off_t,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn wmo_read_bufr_from_file_malloc(
+        f: *mut FILE,
+        headers_only: ::std::os::raw::c_int,
+        size: *mut usize,
+        offset: *mut off_t,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn wmo_read_grib_from_file_malloc(
+        f: *mut FILE,
+        headers_only: ::std::os::raw::c_int,
+        size: *mut usize,
+        offset: *mut off_t,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut ::std::os::raw::c_void;
+}
+unsafe extern "C" {
+    pub fn grib_read_any_from_file(
+        ctx: *mut grib_context,
+        f: *mut FILE,
+        buffer: *mut ::std::os::raw::c_void,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_message_offset(
+        h: *const grib_handle,
+        offset: *mut off_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_get_message_size(h: *const grib_handle, size: *mut usize) -> ::std::os::raw::c_int;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_util_grid_spec {
+    pub grid_type: ::std::os::raw::c_int,
+    pub grid_name: *const ::std::os::raw::c_char,
+    pub Ni: ::std::os::raw::c_long,
+    pub Nj: ::std::os::raw::c_long,
+    pub iDirectionIncrementInDegrees: f64,
+    pub jDirectionIncrementInDegrees: f64,
+    pub longitudeOfFirstGridPointInDegrees: f64,
+    pub longitudeOfLastGridPointInDegrees: f64,
+    pub latitudeOfFirstGridPointInDegrees: f64,
+    pub latitudeOfLastGridPointInDegrees: f64,
+    pub uvRelativeToGrid: ::std::os::raw::c_long,
+    pub latitudeOfSouthernPoleInDegrees: f64,
+    pub longitudeOfSouthernPoleInDegrees: f64,
+    pub angleOfRotationInDegrees: f64,
+    pub iScansNegatively: ::std::os::raw::c_long,
+    pub jScansPositively: ::std::os::raw::c_long,
+    pub N: ::std::os::raw::c_long,
+    pub bitmapPresent: ::std::os::raw::c_long,
+    pub missingValue: f64,
+    pub pl: *const ::std::os::raw::c_long,
+    pub pl_size: ::std::os::raw::c_long,
+    pub truncation: ::std::os::raw::c_long,
+    pub orientationOfTheGridInDegrees: f64,
+    pub DyInMetres: ::std::os::raw::c_long,
+    pub DxInMetres: ::std::os::raw::c_long,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct grib_util_packing_spec {
+    pub packing_type: ::std::os::raw::c_long,
+    pub packing: ::std::os::raw::c_long,
+    pub boustrophedonic: ::std::os::raw::c_long,
+    pub editionNumber: ::std::os::raw::c_long,
+    pub accuracy: ::std::os::raw::c_long,
+    pub bitsPerValue: ::std::os::raw::c_long,
+    pub decimalScaleFactor: ::std::os::raw::c_long,
+    pub computeLaplacianOperator: ::std::os::raw::c_long,
+    pub truncateLaplacian: ::std::os::raw::c_int,
+    pub laplacianOperator: f64,
+    pub deleteLocalDefinition: ::std::os::raw::c_long,
+    pub extra_settings: [grib_values; 80usize],
+    pub extra_settings_count: ::std::os::raw::c_long,
+}
+unsafe extern "C" {
+    pub fn grib_util_set_spec(
+        h: *mut grib_handle,
+        grid_spec: *const grib_util_grid_spec,
+        packing_spec: *const grib_util_packing_spec,
+        flags: ::std::os::raw::c_int,
+        data_values: *const f64,
+        data_values_count: usize,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_handle;
+}
+unsafe extern "C" {
+    pub fn parse_keyval_string(
+        grib_tool: *const ::std::os::raw::c_char,
+        arg: *mut ::std::os::raw::c_char,
+        values_required: ::std::os::raw::c_int,
+        default_type: ::std::os::raw::c_int,
+        values: *mut grib_values,
+        count: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn grib_new_from_file(
+        c: *mut grib_context,
+        f: *mut FILE,
+        headers_only: ::std::os::raw::c_int,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut grib_handle;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct codes_bufr_header {
+    pub message_offset: ::std::os::raw::c_ulong,
+    pub message_size: ::std::os::raw::c_ulong,
+    pub edition: ::std::os::raw::c_long,
+    pub masterTableNumber: ::std::os::raw::c_long,
+    pub bufrHeaderSubCentre: ::std::os::raw::c_long,
+    pub bufrHeaderCentre: ::std::os::raw::c_long,
+    pub updateSequenceNumber: ::std::os::raw::c_long,
+    pub dataCategory: ::std::os::raw::c_long,
+    pub dataSubCategory: ::std::os::raw::c_long,
+    pub masterTablesVersionNumber: ::std::os::raw::c_long,
+    pub localTablesVersionNumber: ::std::os::raw::c_long,
+    pub typicalYear: ::std::os::raw::c_long,
+    pub typicalMonth: ::std::os::raw::c_long,
+    pub typicalDay: ::std::os::raw::c_long,
+    pub typicalHour: ::std::os::raw::c_long,
+    pub typicalMinute: ::std::os::raw::c_long,
+    pub typicalSecond: ::std::os::raw::c_long,
+    pub typicalDate: ::std::os::raw::c_long,
+    pub typicalTime: ::std::os::raw::c_long,
+    pub internationalDataSubCategory: ::std::os::raw::c_long,
+    pub localSectionPresent: ::std::os::raw::c_long,
+    pub ecmwfLocalSectionPresent: ::std::os::raw::c_long,
+    pub rdbType: ::std::os::raw::c_long,
+    pub oldSubtype: ::std::os::raw::c_long,
+    pub rdbSubtype: ::std::os::raw::c_long,
+    pub ident: [::std::os::raw::c_char; 9usize],
+    pub localYear: ::std::os::raw::c_long,
+    pub localMonth: ::std::os::raw::c_long,
+    pub localDay: ::std::os::raw::c_long,
+    pub localHour: ::std::os::raw::c_long,
+    pub localMinute: ::std::os::raw::c_long,
+    pub localSecond: ::std::os::raw::c_long,
+    pub rdbtimeDay: ::std::os::raw::c_long,
+    pub rdbtimeHour: ::std::os::raw::c_long,
+    pub rdbtimeMinute: ::std::os::raw::c_long,
+    pub rdbtimeSecond: ::std::os::raw::c_long,
+    pub rectimeDay: ::std::os::raw::c_long,
+    pub rectimeHour: ::std::os::raw::c_long,
+    pub rectimeMinute: ::std::os::raw::c_long,
+    pub rectimeSecond: ::std::os::raw::c_long,
+    pub restricted: ::std::os::raw::c_long,
+    pub isSatellite: ::std::os::raw::c_long,
+    pub localLongitude1: f64,
+    pub localLatitude1: f64,
+    pub localLongitude2: f64,
+    pub localLatitude2: f64,
+    pub localLatitude: f64,
+    pub localLongitude: f64,
+    pub localNumberOfObservations: ::std::os::raw::c_long,
+    pub satelliteID: ::std::os::raw::c_long,
+    pub qualityControl: ::std::os::raw::c_long,
+    pub newSubtype: ::std::os::raw::c_long,
+    pub daLoop: ::std::os::raw::c_long,
+    pub numberOfSubsets: ::std::os::raw::c_ulong,
+    pub observedData: ::std::os::raw::c_long,
+    pub compressedData: ::std::os::raw::c_long,
+}
+pub type codes_assertion_failed_proc =
+    ::std::option::Option<unsafe extern "C" fn(message: *const ::std::os::raw::c_char)>;
+unsafe extern "C" {
+    pub fn codes_set_codes_assertion_failed_proc(proc_: codes_assertion_failed_proc);
+}
+pub type codes_values = grib_values;
+pub type codes_key_value_list = grib_key_value_list;
+pub type codes_handle = grib_handle;
+pub type codes_multi_handle = grib_multi_handle;
+pub type codes_context = grib_context;
+pub type codes_iterator = grib_iterator;
+pub type codes_nearest = grib_nearest;
+pub type codes_keys_iterator = grib_keys_iterator;
+pub type codes_bufr_keys_iterator = bufr_keys_iterator;
+pub type codes_fieldset = grib_fieldset;
+pub type codes_order_by = grib_order_by;
+pub type codes_where = grib_where;
+pub type codes_sarray = grib_sarray;
+pub type codes_oarray = grib_oarray;
+pub type codes_darray = grib_darray;
+pub type codes_iarray = grib_iarray;
+pub type codes_vdarray = grib_vdarray;
+pub type codes_vsarray = grib_vsarray;
+pub type codes_viarray = grib_viarray;
+pub type codes_string_list = grib_string_list;
+pub type codes_util_packing_spec = grib_util_packing_spec;
+pub type codes_util_grid_spec = grib_util_grid_spec;
+unsafe extern "C" {
+    pub fn codes_fieldset_new_from_files(
+        c: *mut codes_context,
+        filenames: *mut *const ::std::os::raw::c_char,
+        nfiles: ::std::os::raw::c_int,
+        keys: *mut *const ::std::os::raw::c_char,
+        nkeys: ::std::os::raw::c_int,
+        where_string: *const ::std::os::raw::c_char,
+        order_by_string: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_fieldset;
+}
+unsafe extern "C" {
+    pub fn codes_fieldset_delete(set: *mut codes_fieldset);
+}
+unsafe extern "C" {
+    pub fn codes_fieldset_rewind(set: *mut codes_fieldset);
+}
+unsafe extern "C" {
+    pub fn codes_fieldset_apply_order_by(
+        set: *mut codes_fieldset,
+        order_by_string: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_fieldset_next_handle(
+        set: *mut codes_fieldset,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_fieldset_count(set: *const codes_fieldset) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_values_check(
+        h: *mut codes_handle,
+        values: *mut codes_values,
+        count: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+pub type codes_index = grib_index;
+unsafe extern "C" {
+    pub fn codes_index_new_from_file(
+        c: *mut codes_context,
+        filename: *const ::std::os::raw::c_char,
+        keys: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_index;
+}
+unsafe extern "C" {
+    pub fn codes_index_new(
+        c: *mut codes_context,
+        keys: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_index;
+}
+unsafe extern "C" {
+    pub fn codes_index_add_file(
+        index: *mut codes_index,
+        filename: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_index_write(
+        index: *mut codes_index,
+        filename: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_index_read(
+        c: *mut codes_context,
+        filename: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_index;
+}
+unsafe extern "C" {
+    pub fn codes_index_get_size(
+        index: *const codes_index,
+        key: *const ::std::os::raw::c_char,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_index_get_long(
+        index: *const codes_index,
+        key: *const ::std::os::raw::c_char,
+        values: *mut ::std::os::raw::c_long,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_index_get_double(
+        index: *const codes_index,
+        key: *const ::std::os::raw::c_char,
+        values: *mut f64,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_index_get_string(
+        index: *const codes_index,
+        key: *const ::std::os::raw::c_char,
+        values: *mut *mut ::std::os::raw::c_char,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_index_select_long(
+        index: *mut codes_index,
+        key: *const ::std::os::raw::c_char,
+        value: ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_index_select_double(
+        index: *mut codes_index,
+        key: *const ::std::os::raw::c_char,
+        value: f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_index_select_string(
+        index: *mut codes_index,
+        key: *const ::std::os::raw::c_char,
+        value: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_handle_new_from_index(
+        index: *mut codes_index,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_index_delete(index: *mut codes_index);
+}
+unsafe extern "C" {
+    pub fn codes_count_in_file(
+        c: *mut codes_context,
+        f: *mut FILE,
+        n: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_count_in_filename(
+        c: *mut codes_context,
+        filename: *const ::std::os::raw::c_char,
+        n: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_handle_new_from_file(
+        c: *mut codes_context,
+        f: *mut FILE,
+        product: ProductKind,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_grib_handle_new_from_file(
+        c: *mut codes_context,
+        f: *mut FILE,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_handle_new_from_file(
+        c: *mut codes_context,
+        f: *mut FILE,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_write_message(
+        h: *const codes_handle,
+        file: *const ::std::os::raw::c_char,
+        mode: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_util_sections_copy(
+        hfrom: *mut codes_handle,
+        hto: *mut codes_handle,
+        what: ::std::os::raw::c_int,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_grib_util_get_param_id(
+        mars_param: *const ::std::os::raw::c_char,
+    ) -> *mut codes_string_list;
+}
+unsafe extern "C" {
+    pub fn codes_grib_util_get_mars_param(
+        param_id: *const ::std::os::raw::c_char,
+    ) -> *mut codes_string_list;
+}
+unsafe extern "C" {
+    pub fn codes_handle_new_from_message(
+        c: *mut codes_context,
+        data: *const ::std::os::raw::c_void,
+        data_len: usize,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_grib_handle_new_from_multi_message(
+        c: *mut codes_context,
+        data: *mut *mut ::std::os::raw::c_void,
+        data_len: *mut usize,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_handle_new_from_message_copy(
+        c: *mut codes_context,
+        data: *const ::std::os::raw::c_void,
+        data_len: usize,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_grib_handle_new_from_samples(
+        c: *mut codes_context,
+        sample_name: *const ::std::os::raw::c_char,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_handle_new_from_samples(
+        c: *mut codes_context,
+        sample_name: *const ::std::os::raw::c_char,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_handle_new_from_samples(
+        c: *mut codes_context,
+        sample_name: *const ::std::os::raw::c_char,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_handle_clone(h: *const codes_handle) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_handle_clone_headers_only(h: *const codes_handle) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_handle_delete(h: *mut codes_handle) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_multi_handle_new(c: *mut codes_context) -> *mut codes_multi_handle;
+}
+unsafe extern "C" {
+    pub fn codes_grib_multi_handle_append(
+        h: *mut codes_handle,
+        start_section: ::std::os::raw::c_int,
+        mh: *mut codes_multi_handle,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_multi_handle_delete(mh: *mut codes_multi_handle) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_multi_handle_write(
+        mh: *mut codes_multi_handle,
+        f: *mut FILE,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_message(
+        h: *const codes_handle,
+        message: *mut *const ::std::os::raw::c_void,
+        message_length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_message_copy(
+        h: *const codes_handle,
+        message: *mut ::std::os::raw::c_void,
+        message_length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_iterator_new(
+        h: *const codes_handle,
+        flags: ::std::os::raw::c_ulong,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_iterator;
+}
+unsafe extern "C" {
+    pub fn codes_grib_get_data(
+        h: *const codes_handle,
+        lats: *mut f64,
+        lons: *mut f64,
+        values: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_iterator_next(
+        i: *mut codes_iterator,
+        lat: *mut f64,
+        lon: *mut f64,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_iterator_previous(
+        i: *mut codes_iterator,
+        lat: *mut f64,
+        lon: *mut f64,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_iterator_has_next(i: *mut codes_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_iterator_reset(i: *mut codes_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_iterator_delete(i: *mut codes_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_nearest_new(
+        h: *const codes_handle,
+        error: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_nearest;
+}
+unsafe extern "C" {
+    pub fn codes_grib_nearest_find(
+        nearest: *mut codes_nearest,
+        h: *const codes_handle,
+        inlat: f64,
+        inlon: f64,
+        flags: ::std::os::raw::c_ulong,
+        outlats: *mut f64,
+        outlons: *mut f64,
+        values: *mut f64,
+        distances: *mut f64,
+        indexes: *mut ::std::os::raw::c_int,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_nearest_delete(nearest: *mut codes_nearest) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_nearest_find_multiple(
+        h: *const codes_handle,
+        is_lsm: ::std::os::raw::c_int,
+        inlats: *const f64,
+        inlons: *const f64,
+        npoints: ::std::os::raw::c_long,
+        outlats: *mut f64,
+        outlons: *mut f64,
+        values: *mut f64,
+        distances: *mut f64,
+        indexes: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_offset(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        offset: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_size(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_length(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_long(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        value: *mut ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_double(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_float(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        value: *mut f32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_double_element(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        i: ::std::os::raw::c_int,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_float_element(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        i: ::std::os::raw::c_int,
+        value: *mut f32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_double_elements(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        index_array: *const ::std::os::raw::c_int,
+        size: ::std::os::raw::c_long,
+        value: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_float_elements(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        index_array: *const ::std::os::raw::c_int,
+        size: ::std::os::raw::c_long,
+        value: *mut f32,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_string(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        mesg: *mut ::std::os::raw::c_char,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_string_array(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut *mut ::std::os::raw::c_char,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_bytes(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        bytes: *mut ::std::os::raw::c_uchar,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_double_array(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut f64,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_float_array(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut f32,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_long_array(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut ::std::os::raw::c_long,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_copy_namespace(
+        dest: *mut codes_handle,
+        name: *const ::std::os::raw::c_char,
+        src: *mut codes_handle,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_long(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        val: ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_double(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        val: f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_string(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        mesg: *const ::std::os::raw::c_char,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_bytes(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        bytes: *const ::std::os::raw::c_uchar,
+        length: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_double_array(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const f64,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_float_array(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const f32,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_force_double_array(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const f64,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_force_float_array(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const f32,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_long_array(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *const ::std::os::raw::c_long,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_string_array(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+        vals: *mut *const ::std::os::raw::c_char,
+        length: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_dump_content(
+        h: *const codes_handle,
+        out: *mut FILE,
+        mode: *const ::std::os::raw::c_char,
+        option_flags: ::std::os::raw::c_ulong,
+        arg: *mut ::std::os::raw::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn codes_dump_action_tree(c: *mut codes_context, f: *mut FILE);
+}
+pub type codes_free_proc = ::std::option::Option<
+    unsafe extern "C" fn(c: *const codes_context, data: *mut ::std::os::raw::c_void),
+>;
+pub type codes_malloc_proc = ::std::option::Option<
+    unsafe extern "C" fn(c: *const codes_context, length: usize) -> *mut ::std::os::raw::c_void,
+>;
+pub type codes_realloc_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const codes_context,
+        data: *mut ::std::os::raw::c_void,
+        length: usize,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+pub type codes_log_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const codes_context,
+        level: ::std::os::raw::c_int,
+        mesg: *const ::std::os::raw::c_char,
+    ),
+>;
+pub type codes_print_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const codes_context,
+        descriptor: *mut ::std::os::raw::c_void,
+        mesg: *const ::std::os::raw::c_char,
+    ),
+>;
+pub type codes_data_read_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const codes_context,
+        ptr: *mut ::std::os::raw::c_void,
+        size: usize,
+        stream: *mut ::std::os::raw::c_void,
+    ) -> usize,
+>;
+pub type codes_data_write_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const codes_context,
+        ptr: *const ::std::os::raw::c_void,
+        size: usize,
+        stream: *mut ::std::os::raw::c_void,
+    ) -> usize,
+>;
+pub type codes_data_tell_proc = ::std::option::Option<
+    unsafe extern "C" fn(c: *const codes_context, stream: *mut ::std::os::raw::c_void) -> off_t,
+>;
+pub type codes_data_seek_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const codes_context,
+        offset: off_t,
+        whence: ::std::os::raw::c_int,
+        stream: *mut ::std::os::raw::c_void,
+    ) -> off_t,
+>;
+pub type codes_data_eof_proc = ::std::option::Option<
+    unsafe extern "C" fn(
+        c: *const codes_context,
+        stream: *mut ::std::os::raw::c_void,
+    ) -> ::std::os::raw::c_int,
+>;
+unsafe extern "C" {
+    pub fn codes_context_get_default() -> *mut codes_context;
+}
+unsafe extern "C" {
+    pub fn codes_context_delete(c: *mut codes_context);
+}
+unsafe extern "C" {
+    pub fn codes_gts_header_on(c: *mut codes_context);
+}
+unsafe extern "C" {
+    pub fn codes_gts_header_off(c: *mut codes_context);
+}
+unsafe extern "C" {
+    pub fn codes_gribex_mode_on(c: *mut codes_context);
+}
+unsafe extern "C" {
+    pub fn codes_get_gribex_mode(c: *mut codes_context) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_gribex_mode_off(c: *mut codes_context);
+}
+unsafe extern "C" {
+    pub fn codes_bufr_multi_element_constant_arrays_on(c: *mut codes_context);
+}
+unsafe extern "C" {
+    pub fn codes_bufr_multi_element_constant_arrays_off(c: *mut codes_context);
+}
+unsafe extern "C" {
+    pub fn codes_context_set_definitions_path(
+        c: *mut codes_context,
+        path: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn codes_context_set_samples_path(
+        c: *mut codes_context,
+        path: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn codes_context_set_memory_proc(
+        c: *mut codes_context,
+        p_malloc: codes_malloc_proc,
+        p_free: codes_free_proc,
+        p_realloc: codes_realloc_proc,
+    );
+}
+unsafe extern "C" {
+    pub fn codes_context_set_persistent_memory_proc(
+        c: *mut codes_context,
+        p_malloc: codes_malloc_proc,
+        p_free: codes_free_proc,
+    );
+}
+unsafe extern "C" {
+    pub fn codes_context_set_buffer_memory_proc(
+        c: *mut codes_context,
+        p_malloc: codes_malloc_proc,
+        p_free: codes_free_proc,
+        p_realloc: codes_realloc_proc,
+    );
+}
+unsafe extern "C" {
+    pub fn codes_context_set_print_proc(c: *mut codes_context, p_print: codes_print_proc);
+}
+unsafe extern "C" {
+    pub fn codes_context_set_logging_proc(c: *mut codes_context, p_log: codes_log_proc);
+}
+unsafe extern "C" {
+    pub fn codes_grib_multi_support_on(c: *mut codes_context);
+}
+unsafe extern "C" {
+    pub fn codes_grib_multi_support_off(c: *mut codes_context);
+}
+unsafe extern "C" {
+    pub fn codes_grib_multi_support_reset_file(c: *mut codes_context, f: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn codes_samples_path(c: *const codes_context) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_definition_path(c: *const codes_context) -> *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_get_api_version() -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn codes_get_git_sha1() -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_get_build_date() -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_get_package_name() -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_print_api_version(out: *mut FILE);
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_new(
+        h: *mut codes_handle,
+        filter_flags: ::std::os::raw::c_ulong,
+        name_space: *const ::std::os::raw::c_char,
+    ) -> *mut codes_keys_iterator;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_copy_data_return_copied_keys(
+        hin: *mut codes_handle,
+        hout: *mut codes_handle,
+        nkeys: *mut usize,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut *mut ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_copy_data(
+        hin: *mut codes_handle,
+        hout: *mut codes_handle,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_next(kiter: *mut codes_keys_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_get_name(
+        kiter: *const codes_keys_iterator,
+    ) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_delete(kiter: *mut codes_keys_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_rewind(kiter: *mut codes_keys_iterator) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_set_flags(
+        kiter: *mut codes_keys_iterator,
+        flags: ::std::os::raw::c_ulong,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_get_long(
+        kiter: *const codes_keys_iterator,
+        v: *mut ::std::os::raw::c_long,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_get_double(
+        kiter: *const codes_keys_iterator,
+        v: *mut f64,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_get_float(
+        kiter: *const codes_keys_iterator,
+        v: *mut f32,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_get_string(
+        kiter: *const codes_keys_iterator,
+        v: *mut ::std::os::raw::c_char,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_keys_iterator_get_bytes(
+        kiter: *const codes_keys_iterator,
+        v: *mut ::std::os::raw::c_uchar,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_update_sections_lengths(h: *mut codes_handle);
+}
+unsafe extern "C" {
+    pub fn codes_get_error_message(code: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_get_type_name(type_: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn codes_get_native_type(
+        h: *const codes_handle,
+        name: *const ::std::os::raw::c_char,
+        type_: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_check(
+        call: *const ::std::os::raw::c_char,
+        file: *const ::std::os::raw::c_char,
+        line: ::std::os::raw::c_int,
+        e: ::std::os::raw::c_int,
+        msg: *const ::std::os::raw::c_char,
+    );
+}
+unsafe extern "C" {
+    pub fn codes_set_values(
+        h: *mut codes_handle,
+        codes_values: *mut codes_values,
+        arg_count: usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_handle_new_from_partial_message_copy(
+        c: *mut codes_context,
+        data: *const ::std::os::raw::c_void,
+        size: usize,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_handle_new_from_partial_message(
+        c: *mut codes_context,
+        data: *const ::std::os::raw::c_void,
+        buflen: usize,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_is_missing(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_is_defined(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_key_is_header(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_key_is_coordinate(
+        h: *const codes_handle,
+        key: *const ::std::os::raw::c_char,
+        err: *mut ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_set_missing(
+        h: *mut codes_handle,
+        key: *const ::std::os::raw::c_char,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_gaussian_latitudes(
+        truncation: ::std::os::raw::c_long,
+        latitudes: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_julian_to_datetime(
+        jd: f64,
+        year: *mut ::std::os::raw::c_long,
+        month: *mut ::std::os::raw::c_long,
+        day: *mut ::std::os::raw::c_long,
+        hour: *mut ::std::os::raw::c_long,
+        minute: *mut ::std::os::raw::c_long,
+        second: *mut ::std::os::raw::c_long,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_datetime_to_julian(
+        year: ::std::os::raw::c_long,
+        month: ::std::os::raw::c_long,
+        day: ::std::os::raw::c_long,
+        hour: ::std::os::raw::c_long,
+        minute: ::std::os::raw::c_long,
+        second: ::std::os::raw::c_long,
+        jd: *mut f64,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_julian_to_date(jdate: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn codes_date_to_julian(ddate: ::std::os::raw::c_long) -> ::std::os::raw::c_long;
+}
+unsafe extern "C" {
+    pub fn codes_get_reduced_row(
+        pl: ::std::os::raw::c_long,
+        lon_first: f64,
+        lon_last: f64,
+        npoints: *mut ::std::os::raw::c_long,
+        ilon_first: *mut ::std::os::raw::c_long,
+        ilon_last: *mut ::std::os::raw::c_long,
+    );
+}
+unsafe extern "C" {
+    pub fn codes_get_reduced_row_p(
+        pl: ::std::os::raw::c_long,
+        lon_first: f64,
+        lon_last: f64,
+        npoints: *mut ::std::os::raw::c_long,
+        olon_first: *mut f64,
+        olon_last: *mut f64,
+    );
+}
+unsafe extern "C" {
+    pub fn codes_get_message_offset(
+        h: *const codes_handle,
+        offset: *mut off_t,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_message_size(
+        h: *const codes_handle,
+        size: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_get_product_kind(
+        h: *const codes_handle,
+        product_kind: *mut ProductKind,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_check_message_header(
+        bytes: *const ::std::os::raw::c_void,
+        length: usize,
+        product: ProductKind,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_check_message_footer(
+        bytes: *const ::std::os::raw::c_void,
+        length: usize,
+        product: ProductKind,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_grib_util_set_spec(
+        h: *mut codes_handle,
+        grid_spec: *const codes_util_grid_spec,
+        packing_spec: *const codes_util_packing_spec,
+        flags: ::std::os::raw::c_int,
+        data_values: *const f64,
+        data_values_count: usize,
+        err: *mut ::std::os::raw::c_int,
+    ) -> *mut codes_handle;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_extract_headers_malloc(
+        c: *mut codes_context,
+        filename: *const ::std::os::raw::c_char,
+        result: *mut *mut codes_bufr_header,
+        num_messages: *mut ::std::os::raw::c_int,
+        strict_mode: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_bufr_header_get_string(
+        bh: *mut codes_bufr_header,
+        key: *const ::std::os::raw::c_char,
+        val: *mut ::std::os::raw::c_char,
+        len: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_extract_offsets_malloc(
+        c: *mut codes_context,
+        filename: *const ::std::os::raw::c_char,
+        product: ProductKind,
+        offsets: *mut *mut off_t,
+        num_messages: *mut ::std::os::raw::c_int,
+        strict_mode: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn codes_extract_offsets_sizes_malloc(
+        c: *mut codes_context,
+        filename: *const ::std::os::raw::c_char,
+        product: ProductKind,
+        offsets: *mut *mut off_t,
+        sizes: *mut *mut usize,
+        num_messages: *mut ::std::os::raw::c_int,
+        strict_mode: ::std::os::raw::c_int,
+    ) -> ::std::os::raw::c_int;
+}
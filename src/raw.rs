@@ -0,0 +1,4 @@
+//! Building blocks for safe wrappers operating directly on pointers returned by ecCodes.
+
+pub mod borrowed;
+pub mod owned;
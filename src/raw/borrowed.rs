@@ -0,0 +1,120 @@
+//! Handles created without copying messages from buffers they borrow.
+//!
+//! `codes_handle_new_from_message` does not copy the message, so the buffer must outlive the handle.
+//! [`BorrowedHandle`] expresses this with a lifetime. With the `mmap` feature, `MappedFile`
+//! provides such handles for messages in a memory-mapped file.
+
+use std::{marker::PhantomData, os::raw::c_int, ptr::NonNull};
+
+use crate::{
+    error::{CodesError, check},
+    *,
+};
+
+/// Handle of a message in a buffer borrowed for `'a`, deleted with `codes_handle_delete` when dropped.
+///
+/// ecCodes may write into the buffer of the message when keys are set,
+/// so the handle must be used only with functions reading the message.
+/// To modify the message, create a handle with `codes_handle_new_from_message_copy` instead.
+#[derive(Debug)]
+pub struct BorrowedHandle<'a> {
+    handle: NonNull<codes_handle>,
+    _message: PhantomData<&'a [u8]>,
+}
+
+impl<'a> BorrowedHandle<'a> {
+    /// Creates a handle of the message in `message` on the default context,
+    /// holding the [`CONTEXT`](crate::sync::CONTEXT) lock while definitions are parsed.
+    ///
+    /// Returns [`CodesError::NullHandle`] if ecCodes cannot create the handle, eg. when the message is invalid.
+    pub fn new(message: &'a [u8]) -> Result<Self, CodesError> {
+        let handle = {
+            let _lock = crate::sync::CONTEXT.lock();
+
+            unsafe {
+                codes_handle_new_from_message(
+                    codes_context_get_default(),
+                    message.as_ptr().cast(),
+                    message.len(),
+                )
+            }
+        };
+
+        let handle = NonNull::new(handle).map(|handle| BorrowedHandle {
+            handle,
+            _message: PhantomData,
+        });
+
+        // reports assertion failure recorded while the handle was created
+        check(CODES_SUCCESS as c_int)?;
+
+        handle.ok_or(CodesError::NullHandle)
+    }
+
+    /// Returns the handle to pass to ecCodes functions reading the message.
+    pub fn as_ptr(&self) -> *mut codes_handle {
+        self.handle.as_ptr()
+    }
+}
+
+impl Drop for BorrowedHandle<'_> {
+    fn drop(&mut self) {
+        // the borrowed buffer is not released by ecCodes
+        unsafe {
+            codes_handle_delete(self.handle.as_ptr());
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+pub use mapped::MappedFile;
+
+#[cfg(feature = "mmap")]
+mod mapped {
+    use std::{fs::File, io, path::Path};
+
+    use memmap2::Mmap;
+
+    #[cfg(eccodes_2_34)]
+    use super::BorrowedHandle;
+    #[cfg(eccodes_2_34)]
+    use crate::{ProductKind, error::CodesError, scan::scan_messages};
+
+    /// Read-only memory-mapped file providing [`BorrowedHandle`](super::BorrowedHandle)s of messages it contains.
+    #[derive(Debug)]
+    pub struct MappedFile {
+        map: Mmap,
+    }
+
+    impl MappedFile {
+        /// Maps the file at `path` into memory.
+        ///
+        /// # Safety
+        ///
+        /// The file must not be modified or truncated while it is mapped,
+        /// as handles would observe the changes or access unmapped memory.
+        pub unsafe fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+            let file = File::open(path)?;
+            let map = unsafe { Mmap::map(&file)? };
+
+            Ok(MappedFile { map })
+        }
+
+        /// Returns the content of the file.
+        pub fn as_bytes(&self) -> &[u8] {
+            &self.map
+        }
+
+        /// Returns handles of messages of `product` kind found in the file with [`scan_messages`],
+        /// in the order they appear. Available with ecCodes 2.34 and newer.
+        #[cfg(eccodes_2_34)]
+        pub fn handles(
+            &self,
+            product: ProductKind,
+        ) -> impl Iterator<Item = Result<BorrowedHandle<'_>, CodesError>> {
+            scan_messages(&self.map, product)
+                .into_iter()
+                .map(|(offset, length, _)| BorrowedHandle::new(&self.map[offset..offset + length]))
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Owning wrappers of ecCodes objects that release them with the matching `*_delete` function on drop.
+//!
+//! Each wrapper holds a non-null pointer, which can be passed to ecCodes functions with `as_ptr`,
+//! and can be converted from and into a raw pointer with `from_raw` and `into_raw`.
+//! Handles, indexes, fieldsets and multi-handles can be sent to other threads.
+
+use std::{mem, ptr::NonNull};
+
+use crate::*;
+
+macro_rules! owned_pointers {
+    ($( $(#[$meta:meta])* $name:ident($pointee:ty) => $delete:ident; )*) => {
+        $(
+            $(#[$meta])*
+            #[doc = ""]
+            #[doc = concat!("Released with [`", stringify!($delete), "`] when dropped.")]
+            #[derive(Debug)]
+            pub struct $name(NonNull<$pointee>);
+
+            impl $name {
+                /// Takes ownership of `ptr`, returns `None` if it is null.
+                ///
+                /// # Safety
+                ///
+                #[doc = concat!(
+                    "`ptr` must be a valid pointer that can be released with [`",
+                    stringify!($delete),
+                    "`] and that is not owned by anything else."
+                )]
+                pub unsafe fn from_raw(ptr: *mut $pointee) -> Option<Self> {
+                    crate::version::guard();
+                    NonNull::new(ptr).map(Self)
+                }
+
+                /// Returns the owned pointer without giving up ownership.
+                pub fn as_ptr(&self) -> *mut $pointee {
+                    self.0.as_ptr()
+                }
+
+                /// Gives up ownership and returns the pointer, which must then be released manually.
+                pub fn into_raw(self) -> *mut $pointee {
+                    let ptr = self.0.as_ptr();
+                    mem::forget(self);
+                    ptr
+                }
+            }
+
+            impl Drop for $name {
+                fn drop(&mut self) {
+                    // errors cannot be reported from drop and ecCodes releases the object anyway
+                    unsafe {
+                        $delete(self.0.as_ptr());
+                    }
+                }
+            }
+        )*
+    };
+}
+
+owned_pointers! {
+    /// Owned `codes_handle` of a single message.
+    OwnedHandle(codes_handle) => codes_handle_delete;
+    /// Owned `codes_index` of messages from one or more files.
+    OwnedIndex(codes_index) => codes_index_delete;
+    /// Owned `codes_keys_iterator` over keys of a GRIB or GTS message.
+    OwnedKeysIterator(codes_keys_iterator) => codes_keys_iterator_delete;
+    /// Owned `codes_bufr_keys_iterator` over keys of a BUFR message.
+    OwnedBufrKeysIterator(codes_bufr_keys_iterator) => codes_bufr_keys_iterator_delete;
+    /// Owned `codes_iterator` over grid points of a GRIB message.
+    OwnedGribIterator(codes_iterator) => codes_grib_iterator_delete;
+    /// Owned `codes_nearest` for finding grid points of a GRIB message nearest to a location.
+    OwnedNearest(codes_nearest) => codes_grib_nearest_delete;
+    /// Owned `codes_fieldset` of GRIB messages selected from files.
+    OwnedFieldset(codes_fieldset) => codes_fieldset_delete;
+    /// Owned `codes_multi_handle` collecting GRIB messages into a multi-field message.
+    OwnedMultiHandle(codes_multi_handle) => codes_grib_multi_handle_delete;
+}
+
+// SAFETY: ecCodes objects have no thread affinity, so an object that is not shared can be used
+// and released on another thread. Functions using the shared context still have to be serialized
+// with `sync::CONTEXT` when ecCodes is not thread-safe. Iterators and nearest objects are not `Send`,
+// as they point to a handle owned elsewhere, which could then be used by two threads at once.
+unsafe impl Send for OwnedHandle {}
+unsafe impl Send for OwnedIndex {}
+unsafe impl Send for OwnedFieldset {}
+unsafe impl Send for OwnedMultiHandle {}
@@ -0,0 +1,361 @@
+//! Reading messages from any [`Read`] source with `wmo_read_any_from_stream_malloc`.
+//!
+//! [`MessageReader`] lets ecCodes find GRIB, BUFR, GTS and other WMO messages in a stream,
+//! such as a socket or a decompressor, without writing it to a file first.
+//! ecCodes reads the stream byte by byte when looking for the start of a message,
+//! so unbuffered sources should be wrapped in [`BufReader`](std::io::BufReader).
+
+use std::{
+    any::Any,
+    io::{self, Read},
+    os::raw::{c_int, c_long, c_void},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice,
+};
+
+use crate::{error::CodesError, *};
+
+/// Iterator over messages read from a [`Read`] source, yielding owned bytes of each message.
+///
+/// Iteration ends at the end of the stream or after an I/O error of the source.
+/// Errors reported by ecCodes, eg. for a malformed message, do not end the iteration.
+#[derive(Debug)]
+pub struct MessageReader<R> {
+    inner: R,
+    finished: bool,
+}
+
+/// State passed to the stream procedure, which must not unwind or fail into ecCodes.
+struct Stream<'a, R> {
+    reader: &'a mut R,
+    error: Option<io::Error>,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+impl<R: Read> MessageReader<R> {
+    /// Creates a reader of messages from `inner`.
+    pub fn new(inner: R) -> Self {
+        MessageReader {
+            inner,
+            finished: false,
+        }
+    }
+
+    /// Returns a reference to the underlying source.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying source.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying source, positioned after the last message read.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads the next message, returns `None` at the end of the stream.
+    ///
+    /// Errors reported by ecCodes are returned as [`io::Error`] wrapping [`CodesError`].
+    pub fn read_message(&mut self) -> io::Result<Option<Vec<u8>>> {
+        crate::version::guard();
+
+        let mut stream = Stream {
+            reader: &mut self.inner,
+            error: None,
+            panic: None,
+        };
+        let mut size = 0;
+        let mut err = 0;
+
+        let buffer = unsafe {
+            wmo_read_any_from_stream_malloc(
+                (&raw mut stream).cast(),
+                Some(read_stream::<R>),
+                &mut size,
+                &mut err,
+            )
+        };
+
+        // message is allocated by ecCodes with malloc
+        let message = (!buffer.is_null()).then(|| unsafe {
+            let message = slice::from_raw_parts(buffer.cast::<u8>(), size).to_vec();
+            libc::free(buffer);
+            message
+        });
+
+        if let Some(payload) = stream.panic {
+            panic::resume_unwind(payload);
+        }
+
+        if let Some(error) = stream.error {
+            return Err(error);
+        }
+
+        match err {
+            0 => Ok(Some(message.unwrap_or_default())),
+            err if err == GRIB_END_OF_FILE as c_int => Ok(None),
+            err => Err(io::Error::other(
+                CodesError::try_from(err).unwrap_or(CodesError::Unknown(err)),
+            )),
+        }
+    }
+}
+
+impl<R: Read> Iterator for MessageReader<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let result = self.read_message();
+
+        match &result {
+            Ok(None) => self.finished = true,
+            Err(err) if err.get_ref().is_none_or(|e| !e.is::<CodesError>()) => self.finished = true,
+            _ => (),
+        }
+
+        result.transpose()
+    }
+}
+
+/// Fills `buffer` with exactly `len` bytes from the stream, as ecCodes treats a shorter read as the end of file.
+/// Returns the number of bytes read, 0 if `len` is not positive, or -1 on error.
+unsafe extern "C" fn read_stream<R: Read>(
+    data: *mut c_void,
+    buffer: *mut c_void,
+    len: c_long,
+) -> c_long {
+    let stream = unsafe { &mut *data.cast::<Stream<R>>() };
+
+    if stream.error.is_some() || stream.panic.is_some() {
+        return -1;
+    }
+
+    let Ok(len) = usize::try_from(len) else {
+        return 0;
+    };
+    if len == 0 {
+        return 0;
+    }
+
+    // buffer is allocated by ecCodes with malloc and has to be initialized before it is referenced
+    let buffer = unsafe {
+        ptr::write_bytes(buffer.cast::<u8>(), 0, len);
+        slice::from_raw_parts_mut(buffer.cast::<u8>(), len)
+    };
+    let mut filled = 0;
+
+    while filled < buffer.len() {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            stream.reader.read(&mut buffer[filled..])
+        }));
+
+        match result {
+            Ok(Ok(0)) => break,
+            Ok(Ok(n)) => filled += n,
+            Ok(Err(err)) if err.kind() == io::ErrorKind::Interrupted => (),
+            Ok(Err(err)) => {
+                stream.error = Some(err);
+                return -1;
+            }
+            Err(payload) => {
+                stream.panic = Some(payload);
+                return -1;
+            }
+        }
+    }
+
+    filled as c_long
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    /// Source returning at most `chunk` bytes per read, optionally interrupted before every read.
+    struct Chunked<R> {
+        inner: R,
+        chunk: usize,
+        interrupt: bool,
+        interrupted: bool,
+    }
+
+    impl<R: Read> Chunked<R> {
+        fn new(inner: R, chunk: usize, interrupt: bool) -> Self {
+            Chunked {
+                inner,
+                chunk,
+                interrupt,
+                interrupted: false,
+            }
+        }
+    }
+
+    impl<R: Read> Read for Chunked<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.interrupt && !self.interrupted {
+                self.interrupted = true;
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            self.interrupted = false;
+
+            let len = buf.len().min(self.chunk);
+            self.inner.read(&mut buf[..len])
+        }
+    }
+
+    /// Source failing after `inner` is exhausted.
+    struct Failing<R>(R);
+
+    impl<R: Read> Read for Failing<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.read(buf)? {
+                0 => Err(io::Error::other("connection reset")),
+                n => Ok(n),
+            }
+        }
+    }
+
+    struct Panicking;
+
+    impl Read for Panicking {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            panic!("reader panicked")
+        }
+    }
+
+    fn stream<R>(reader: &mut R) -> Stream<'_, R> {
+        Stream {
+            reader,
+            error: None,
+            panic: None,
+        }
+    }
+
+    /// Calls the stream procedure for `len` bytes, returns its result and the bytes read.
+    fn read_into<R: Read>(stream: &mut Stream<R>, len: usize) -> (c_long, Vec<u8>) {
+        let mut buffer = vec![0xff; len];
+        let read = unsafe {
+            read_stream::<R>(
+                (stream as *mut Stream<R>).cast(),
+                buffer.as_mut_ptr().cast(),
+                len as c_long,
+            )
+        };
+
+        buffer.truncate(usize::try_from(read).unwrap_or(0));
+        (read, buffer)
+    }
+
+    #[cfg(not(feature = "docs"))]
+    fn grib2(length: usize, fill: u8) -> Vec<u8> {
+        let mut message = vec![fill; length];
+        message[..8].copy_from_slice(b"GRIB\0\0\0\x02");
+        message[8..16].copy_from_slice(&(length as u64).to_be_bytes());
+        message[length - 4..].copy_from_slice(b"7777");
+        message
+    }
+
+    #[test]
+    fn stream_fills_buffer_from_chunks() {
+        let mut reader = Chunked::new(Cursor::new(b"0123456789"), 3, false);
+        let mut stream = stream(&mut reader);
+
+        assert_eq!(read_into(&mut stream, 8), (8, b"01234567".to_vec()));
+        assert_eq!(read_into(&mut stream, 8), (2, b"89".to_vec()));
+        assert_eq!(read_into(&mut stream, 8), (0, Vec::new()));
+    }
+
+    #[test]
+    fn stream_retries_interrupted_reads() {
+        let mut reader = Chunked::new(Cursor::new(b"0123456789"), 4, true);
+        let mut stream = stream(&mut reader);
+
+        assert_eq!(read_into(&mut stream, 10), (10, b"0123456789".to_vec()));
+        assert!(stream.error.is_none());
+    }
+
+    #[test]
+    fn stream_stores_io_error() {
+        let mut reader = Failing(Cursor::new(b"0123"));
+        let mut stream = stream(&mut reader);
+
+        assert_eq!(read_into(&mut stream, 8).0, -1);
+        assert_eq!(
+            stream.error.as_ref().unwrap().to_string(),
+            "connection reset"
+        );
+        assert_eq!(read_into(&mut stream, 8).0, -1);
+    }
+
+    #[test]
+    fn stream_catches_panic() {
+        let mut reader = Panicking;
+        let mut stream = stream(&mut reader);
+
+        assert_eq!(read_into(&mut stream, 8).0, -1);
+        let payload = stream.panic.take().unwrap();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"reader panicked"));
+    }
+
+    #[test]
+    fn stream_ignores_empty_buffer() {
+        let mut reader = Panicking;
+        let mut stream = stream(&mut reader);
+
+        assert_eq!(read_into(&mut stream, 0), (0, Vec::new()));
+        assert!(stream.panic.is_none());
+    }
+
+    // tests below call ecCodes, which is not linked with the `docs` feature
+
+    #[cfg(not(feature = "docs"))]
+    #[test]
+    fn messages_from_chunked_reader() {
+        let messages = [grib2(64, 1), grib2(40, 2), grib2(128, 3)];
+        let data = messages.concat();
+
+        let reader = MessageReader::new(Chunked::new(Cursor::new(data), 7, false));
+        let read = reader.collect::<io::Result<Vec<_>>>().unwrap();
+
+        assert_eq!(read, messages);
+    }
+
+    #[cfg(not(feature = "docs"))]
+    #[test]
+    fn messages_from_interrupted_reader() {
+        let messages = [grib2(64, 1), grib2(40, 2)];
+        let data = messages.concat();
+
+        let reader = MessageReader::new(Chunked::new(Cursor::new(data), 16, true));
+        let read = reader.collect::<io::Result<Vec<_>>>().unwrap();
+
+        assert_eq!(read, messages);
+    }
+
+    #[cfg(not(feature = "docs"))]
+    #[test]
+    fn io_error_ends_iteration() {
+        let mut reader = MessageReader::new(Failing(Cursor::new(grib2(64, 1))));
+
+        assert_eq!(reader.next().unwrap().unwrap(), grib2(64, 1));
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "connection reset");
+        assert!(reader.next().is_none());
+    }
+
+    #[cfg(not(feature = "docs"))]
+    #[test]
+    #[should_panic(expected = "reader panicked")]
+    fn panic_is_resumed() {
+        let _ = MessageReader::new(Panicking).read_message();
+    }
+}
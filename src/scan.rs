@@ -0,0 +1,306 @@
+//! Finding message boundaries in a byte buffer, eg. a memory-mapped file, without copying it.
+//!
+//! ecCodes can extract offsets of messages only from named files, so [`scan_messages`] looks for
+//! message identifiers in the buffer and reads lengths from the message headers. GRIB and BUFR
+//! messages are then validated with `codes_check_message_header` and `codes_check_message_footer`.
+//! GTS bulletins are delimited by `SOH CR CR LF` and `CR CR LF ETX`, METAR and TAF reports
+//! by their identifier and the terminating `=`.
+//!
+//! GRIB 1 messages using the large message encoding (over 8 MB) and BUFR editions 0 and 1,
+//! which do not store the total length in section 0, are not recognised.
+//!
+//! This module requires ecCodes 2.34 or newer.
+
+use std::os::raw::c_int;
+
+use crate::*;
+
+const GTS_START: &[u8] = b"\x01\r\r\n";
+const GTS_END: &[u8] = b"\r\r\n\x03";
+
+/// Scans `data` for messages of `product` kind, or of all kinds with `ProductKind_PRODUCT_ANY`,
+/// and returns their offsets, lengths and kinds in the order they appear.
+///
+/// Bytes between messages are skipped. When a message is found, scanning continues after its end,
+/// so with `ProductKind_PRODUCT_ANY` messages embedded in GTS bulletins are not reported separately.
+pub fn scan_messages(data: &[u8], product: ProductKind) -> Vec<(usize, usize, ProductKind)> {
+    crate::version::guard();
+
+    scan_with(data, product, check_message)
+}
+
+/// Scans `data` as [`scan_messages`] does, validating GRIB and BUFR messages with `is_valid`.
+fn scan_with(
+    data: &[u8],
+    product: ProductKind,
+    is_valid: impl Fn(&[u8], ProductKind) -> bool,
+) -> Vec<(usize, usize, ProductKind)> {
+    let mut messages = Vec::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        match find_message(&data[offset..], product, &is_valid) {
+            Some((length, kind)) => {
+                messages.push((offset, length, kind));
+                offset += length;
+            }
+            None => offset += 1,
+        }
+    }
+
+    messages
+}
+
+/// Returns length and kind of the message starting at the beginning of `data`, if there is one.
+fn find_message(
+    data: &[u8],
+    product: ProductKind,
+    is_valid: &impl Fn(&[u8], ProductKind) -> bool,
+) -> Option<(usize, ProductKind)> {
+    let accepts = |kind| product == ProductKind_PRODUCT_ANY || product == kind;
+
+    let (length, kind) = if data.starts_with(b"GRIB") && accepts(ProductKind_PRODUCT_GRIB) {
+        (grib_length(data)?, ProductKind_PRODUCT_GRIB)
+    } else if data.starts_with(b"BUFR") && accepts(ProductKind_PRODUCT_BUFR) {
+        (bufr_length(data)?, ProductKind_PRODUCT_BUFR)
+    } else if data.starts_with(GTS_START) && accepts(ProductKind_PRODUCT_GTS) {
+        let end = find(&data[GTS_START.len()..], GTS_END)?;
+        (
+            GTS_START.len() + end + GTS_END.len(),
+            ProductKind_PRODUCT_GTS,
+        )
+    } else if data.starts_with(b"METAR") && accepts(ProductKind_PRODUCT_METAR) {
+        (find(data, b"=")? + 1, ProductKind_PRODUCT_METAR)
+    } else if data.starts_with(b"TAF") && accepts(ProductKind_PRODUCT_TAF) {
+        (find(data, b"=")? + 1, ProductKind_PRODUCT_TAF)
+    } else {
+        return None;
+    };
+
+    if kind == ProductKind_PRODUCT_GRIB || kind == ProductKind_PRODUCT_BUFR {
+        let message = data.get(..length)?;
+
+        // ecCodes asserts that the message is longer than the identifier
+        if message.len() <= 8 || !is_valid(message, kind) {
+            return None;
+        }
+    }
+
+    Some((length, kind))
+}
+
+/// Reads total length of GRIB message from section 0.
+fn grib_length(data: &[u8]) -> Option<usize> {
+    match *data.get(7)? {
+        1 => {
+            let length = read_u24(data)?;
+            // large messages set the highest bit and need section 4 to compute the length
+            (length & 0x80_0000 == 0).then_some(length)
+        }
+        2 => {
+            let length = u64::from_be_bytes(data.get(8..16)?.try_into().ok()?);
+            usize::try_from(length).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Reads total length of BUFR message from section 0.
+fn bufr_length(data: &[u8]) -> Option<usize> {
+    match *data.get(7)? {
+        0 | 1 => None,
+        _ => read_u24(data),
+    }
+}
+
+fn read_u24(data: &[u8]) -> Option<usize> {
+    let bytes = data.get(4..7)?;
+    Some(usize::from(bytes[0]) << 16 | usize::from(bytes[1]) << 8 | usize::from(bytes[2]))
+}
+
+fn find(data: &[u8], pattern: &[u8]) -> Option<usize> {
+    data.windows(pattern.len())
+        .position(|window| window == pattern)
+}
+
+fn check_message(message: &[u8], kind: ProductKind) -> bool {
+    let ptr = message.as_ptr().cast();
+    let success = CODES_SUCCESS as c_int;
+
+    unsafe {
+        codes_check_message_header(ptr, message.len(), kind) == success
+            && codes_check_message_footer(ptr, message.len(), kind) == success
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accept_all(_: &[u8], _: ProductKind) -> bool {
+        true
+    }
+
+    fn grib1(length: usize) -> Vec<u8> {
+        let mut message = vec![0; length];
+        message[..4].copy_from_slice(b"GRIB");
+        message[4..7].copy_from_slice(&(length as u32).to_be_bytes()[1..]);
+        message[7] = 1;
+        message[length - 4..].copy_from_slice(b"7777");
+        message
+    }
+
+    fn grib2(length: usize) -> Vec<u8> {
+        let mut message = vec![0; length];
+        message[..4].copy_from_slice(b"GRIB");
+        message[7] = 2;
+        message[8..16].copy_from_slice(&(length as u64).to_be_bytes());
+        message[length - 4..].copy_from_slice(b"7777");
+        message
+    }
+
+    fn bufr(edition: u8, length: usize) -> Vec<u8> {
+        let mut message = vec![0; length];
+        message[..4].copy_from_slice(b"BUFR");
+        message[4..7].copy_from_slice(&(length as u32).to_be_bytes()[1..]);
+        message[7] = edition;
+        message[length - 4..].copy_from_slice(b"7777");
+        message
+    }
+
+    #[test]
+    fn grib1_length() {
+        let mut data = b"junk".to_vec();
+        data.extend(grib1(40));
+        data.extend(b"junk");
+
+        assert_eq!(
+            scan_with(&data, ProductKind_PRODUCT_GRIB, accept_all),
+            [(4, 40, ProductKind_PRODUCT_GRIB)]
+        );
+    }
+
+    #[test]
+    fn grib2_length() {
+        let mut data = grib2(64);
+        data.extend(grib2(32));
+
+        assert_eq!(
+            scan_with(&data, ProductKind_PRODUCT_GRIB, accept_all),
+            [
+                (0, 64, ProductKind_PRODUCT_GRIB),
+                (64, 32, ProductKind_PRODUCT_GRIB)
+            ]
+        );
+    }
+
+    #[test]
+    fn bufr_length() {
+        let data = bufr(4, 48);
+
+        assert_eq!(
+            scan_with(&data, ProductKind_PRODUCT_BUFR, accept_all),
+            [(0, 48, ProductKind_PRODUCT_BUFR)]
+        );
+    }
+
+    #[test]
+    fn bufr_edition_without_length() {
+        let data = bufr(1, 48);
+
+        assert!(scan_with(&data, ProductKind_PRODUCT_ANY, accept_all).is_empty());
+    }
+
+    #[test]
+    fn validated_slice() {
+        let mut data = grib2(32);
+        data.extend(b"trailing");
+
+        let messages = scan_with(&data, ProductKind_PRODUCT_ANY, |message, kind| {
+            kind == ProductKind_PRODUCT_GRIB && message.len() == 32 && message.ends_with(b"7777")
+        });
+
+        assert_eq!(messages, [(0, 32, ProductKind_PRODUCT_GRIB)]);
+        assert!(scan_with(&data, ProductKind_PRODUCT_ANY, |_, _| false).is_empty());
+    }
+
+    #[test]
+    fn truncated_message() {
+        let data = &grib2(64)[..40];
+
+        assert!(scan_with(data, ProductKind_PRODUCT_ANY, accept_all).is_empty());
+    }
+
+    #[test]
+    fn grib1_large_message() {
+        let mut data = grib1(40);
+        data[4] |= 0x80;
+
+        assert!(scan_with(&data, ProductKind_PRODUCT_ANY, accept_all).is_empty());
+    }
+
+    #[test]
+    fn gts_bulletin() {
+        let mut data = GTS_START.to_vec();
+        data.extend(b"123\r\r\nTTAA00 EGRR 011200\r\r\n");
+        data.extend(GTS_END);
+
+        assert_eq!(
+            scan_with(&data, ProductKind_PRODUCT_ANY, accept_all),
+            [(0, data.len(), ProductKind_PRODUCT_GTS)]
+        );
+    }
+
+    #[test]
+    fn gts_bulletin_without_end() {
+        let mut data = GTS_START.to_vec();
+        data.extend(b"123\r\r\nISMS01 EGRR 011200\r\r\n");
+        let header = data.len();
+        data.extend(bufr(4, 48));
+
+        assert!(scan_with(&data, ProductKind_PRODUCT_GTS, accept_all).is_empty());
+        assert_eq!(
+            scan_with(&data, ProductKind_PRODUCT_ANY, accept_all),
+            [(header, 48, ProductKind_PRODUCT_BUFR)]
+        );
+    }
+
+    #[test]
+    fn metar_and_taf() {
+        let metar = b"METAR EPWA 011200Z 27010KT CAVOK=";
+        let taf = b"TAF EPWA 011100Z 0112/0212 27010KT CAVOK=";
+        let data = [&metar[..], b"\n", taf].concat();
+
+        assert_eq!(
+            scan_with(&data, ProductKind_PRODUCT_ANY, accept_all),
+            [
+                (0, metar.len(), ProductKind_PRODUCT_METAR),
+                (metar.len() + 1, taf.len(), ProductKind_PRODUCT_TAF)
+            ]
+        );
+    }
+
+    #[test]
+    fn product_filter() {
+        let mut data = grib2(32);
+        data.extend(bufr(4, 48));
+        data.extend(grib1(40));
+
+        assert_eq!(
+            scan_with(&data, ProductKind_PRODUCT_BUFR, accept_all),
+            [(32, 48, ProductKind_PRODUCT_BUFR)]
+        );
+        assert_eq!(
+            scan_with(&data, ProductKind_PRODUCT_GRIB, accept_all),
+            [
+                (0, 32, ProductKind_PRODUCT_GRIB),
+                (80, 40, ProductKind_PRODUCT_GRIB)
+            ]
+        );
+        assert_eq!(
+            scan_with(&data, ProductKind_PRODUCT_ANY, accept_all).len(),
+            3
+        );
+        assert!(scan_with(&data, ProductKind_PRODUCT_METAR, accept_all).is_empty());
+    }
+}
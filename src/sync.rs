@@ -0,0 +1,66 @@
+//! Lock serializing ecCodes functions that are not safe to call concurrently.
+//!
+//! ecCodes keeps caches of parsed definitions, open files, settings and paths in a context shared
+//! by all handles, which is modified without synchronization by several functions. Creating a handle
+//! parses definitions into the same cache whether the message comes from a file, a buffer or an index,
+//! and the definitions path is read whenever definitions are loaded, so these functions cannot be
+//! split into independent groups and all of them are serialized with [`CONTEXT`].
+//!
+//! | Operation | ecCodes functions |
+//! |---|---|
+//! | creating handles | `codes_handle_new_from_file`, `codes_grib_handle_new_from_file`, `codes_bufr_handle_new_from_file`, `codes_handle_new_from_message`, `codes_handle_new_from_message_copy`, `codes_grib_handle_new_from_multi_message`, `codes_handle_clone`, `codes_handle_new_from_samples`, `codes_grib_handle_new_from_samples`, `codes_bufr_handle_new_from_samples`, `codes_handle_new_from_index` |
+//! | reading files | `codes_count_in_file`, `codes_count_in_filename`, `codes_extract_offsets_malloc`, `codes_extract_offsets_sizes_malloc`, `codes_bufr_extract_headers_malloc`, `codes_fieldset_new_from_files`, `codes_grib_multi_support_reset_file`, `wmo_read_*_from_file`, `wmo_read_*_from_file_malloc` |
+//! | building indexes | `codes_index_new`, `codes_index_new_from_file`, `codes_index_add_file`, `codes_index_read`, `codes_index_write`, `codes_index_select_*`, `codes_index_get_*`, `codes_index_set_product_kind`, `codes_index_set_unpack_bufr` |
+//! | changing the context | `codes_context_set_memory_proc`, `codes_context_set_persistent_memory_proc`, `codes_context_set_buffer_memory_proc`, `codes_context_set_logging_proc`, `codes_context_set_print_proc`, `codes_set_codes_assertion_failed_proc`, `codes_grib_multi_support_on`, `codes_grib_multi_support_off`, `codes_gts_header_on`, `codes_gts_header_off`, `codes_bufr_multi_element_constant_arrays_on`, `codes_bufr_multi_element_constant_arrays_off`, `codes_context_delete` |
+//! | definitions and samples paths | `codes_context_set_definitions_path`, `codes_context_set_samples_path`, `codes_definition_path`, `codes_samples_path` |
+//!
+//! Functions of the `grib_*` API require the lock like their `codes_*` counterparts.
+//!
+//! [`CONTEXT`] uses the same mutex as the deprecated [`CODES_LOCK`](crate::CODES_LOCK),
+//! so code still locking it is serialized with code using this module.
+//!
+//! When ecCodes has been built with thread support (see [`THREAD_SAFE`]), it synchronizes
+//! these functions itself and locking is a no-op.
+//!
+//! The lock does not use poisoning, as it protects ecCodes state and not Rust data,
+//! so a panic of a thread holding the lock does not make other threads fail.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Whether the linked ecCodes has been built with thread support (`ENABLE_ECCODES_THREADS` or `ENABLE_ECCODES_OMP_THREADS`),
+/// as detected from its configuration headers. The `eccodes_thread_safe` cfg is set when this is `true`.
+///
+/// Always `false` when built with the `docs` feature.
+pub const THREAD_SAFE: bool = cfg!(eccodes_thread_safe);
+
+/// Lock for serializing ecCodes functions.
+#[derive(Debug)]
+pub struct CodesLock {
+    mutex: &'static Mutex<()>,
+}
+
+/// Guard returned by [`CodesLock::lock`], the lock is released when it is dropped.
+#[derive(Debug)]
+#[must_use = "the lock is released immediately if the guard is not held"]
+pub struct CodesLockGuard<'a> {
+    _guard: Option<MutexGuard<'a, ()>>,
+}
+
+impl CodesLock {
+    const fn new(mutex: &'static Mutex<()>) -> Self {
+        CodesLock { mutex }
+    }
+
+    /// Blocks the current thread until the lock is acquired.
+    /// Returns immediately when ecCodes is [`THREAD_SAFE`].
+    pub fn lock(&self) -> CodesLockGuard<'_> {
+        let guard =
+            (!THREAD_SAFE).then(|| self.mutex.lock().unwrap_or_else(PoisonError::into_inner));
+
+        CodesLockGuard { _guard: guard }
+    }
+}
+
+/// Lock for all functions using caches, settings or paths of a context, listed in the [module documentation](self).
+#[allow(deprecated)]
+pub static CONTEXT: CodesLock = CodesLock::new(&crate::CODES_LOCK);
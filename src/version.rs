@@ -0,0 +1,103 @@
+//! Verification that ecCodes loaded at runtime matches the version bindings were generated for.
+//!
+//! Bindings are generated from headers at build time, while the library is loaded at runtime,
+//! so after a system upgrade they can differ. Layout of structs passed by value or pointer
+//! (eg. `grib_util_packing_spec`) may change between minor versions, so using bindings
+//! with a different minor version of the library can cause undefined behaviour.
+
+use std::{error::Error, ffi::c_long, fmt};
+
+use crate::*;
+
+/// Version of ecCodes library.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EccodesVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub revision: u32,
+}
+
+impl EccodesVersion {
+    /// Version of ecCodes headers the bindings have been generated from.
+    pub const COMPILED: Self = Self {
+        major: ECCODES_MAJOR_VERSION,
+        minor: ECCODES_MINOR_VERSION,
+        revision: ECCODES_REVISION_VERSION,
+    };
+
+    /// Returns version of ecCodes library loaded at runtime, as reported by `codes_get_api_version`.
+    pub fn runtime() -> Self {
+        let api_version = unsafe { codes_get_api_version() };
+        Self::from_api_version(api_version)
+    }
+
+    /// Decodes version from the `major * 10000 + minor * 100 + revision` format used by ecCodes.
+    fn from_api_version(api_version: c_long) -> Self {
+        let api_version = api_version.max(0) as u32;
+
+        Self {
+            major: api_version / 10000,
+            minor: (api_version / 100) % 100,
+            revision: api_version % 100,
+        }
+    }
+}
+
+impl fmt::Display for EccodesVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.revision)
+    }
+}
+
+/// Error returned when ecCodes loaded at runtime has different
+/// major or minor version than the bindings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VersionMismatch {
+    pub compiled: EccodesVersion,
+    pub runtime: EccodesVersion,
+}
+
+impl fmt::Display for VersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ecCodes {} loaded at runtime does not match version {} the bindings have been generated for",
+            self.runtime, self.compiled
+        )
+    }
+}
+
+impl Error for VersionMismatch {}
+
+/// Compares major and minor version of ecCodes loaded at runtime
+/// with the version the bindings have been generated for.
+///
+/// Differences in revision are accepted, as they do not change the API.
+pub fn check_runtime_version() -> Result<(), VersionMismatch> {
+    let compiled = EccodesVersion::COMPILED;
+    let runtime = EccodesVersion::runtime();
+
+    if (compiled.major, compiled.minor) != (runtime.major, runtime.minor) {
+        return Err(VersionMismatch { compiled, runtime });
+    }
+
+    Ok(())
+}
+
+/// Checks runtime version once per process when the `runtime-version-check` feature is enabled.
+/// Called by helpers of this crate on their first use.
+///
+/// # Panics
+///
+/// If [`check_runtime_version`] fails.
+pub(crate) fn guard() {
+    #[cfg(feature = "runtime-version-check")]
+    {
+        static CHECKED: std::sync::OnceLock<Result<(), VersionMismatch>> =
+            std::sync::OnceLock::new();
+
+        if let Err(mismatch) = CHECKED.get_or_init(check_runtime_version) {
+            panic!("{}", mismatch);
+        }
+    }
+}